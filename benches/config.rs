@@ -0,0 +1,43 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rust_lessons::advanced::config::{Config, OwnedConfig, generate};
+
+// 借用解析器和分配 String 的解析器的耗时对比。
+// 用 `cargo bench --bench config` 运行（release 模式）；可以传入小节数，例如 `cargo bench --bench config -- 2000`。
+// 结果和机器、负载都有关系，所以放在这里而不是课程示例中
+
+fn time<T>(rounds: u32, mut f: impl FnMut() -> T) -> Duration {
+    let start = Instant::now();
+    for _ in 0..rounds {
+        black_box(f());
+    }
+    start.elapsed() / rounds
+}
+
+fn main() {
+    // `cargo bench` 会把 `--bench` 传给每个目标，跳过以 `-` 开头的参数
+    let sections = std::env::args()
+        .skip(1)
+        .find(|arg| !arg.starts_with('-'))
+        .map(|arg| arg.parse().expect("section count must be a number"))
+        .unwrap_or(1000);
+    let keys = 20;
+    let text = generate(sections, keys);
+
+    // 先各跑一次热身，顺便确认两者的结果一样
+    assert_eq!(Config::parse(&text).unwrap().into_owned(), OwnedConfig::parse(&text).unwrap());
+
+    let rounds = 20;
+    let borrowing = time(rounds, || Config::parse(black_box(&text)).unwrap());
+    let allocating = time(rounds, || OwnedConfig::parse(black_box(&text)).unwrap());
+    println!(
+        "{} KiB, {} entries, {} rounds: borrowing {:?}, allocating {:?} ({:.1}x)",
+        text.len() / 1024,
+        sections * keys,
+        rounds,
+        borrowing,
+        allocating,
+        allocating.as_secs_f64() / borrowing.as_secs_f64().max(f64::EPSILON)
+    );
+}
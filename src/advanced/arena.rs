@@ -0,0 +1,541 @@
+use std::cell::{Cell, OnceCell, RefCell};
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+
+// 竞技场分配器与图结构（Arena Allocation）
+// 图里的节点互相引用，还可能成环，用普通的所有权很难表达：谁拥有谁？
+// 竞技场（arena）给出的答案是：所有节点都归竞技场所有，节点之间只保存 `&'arena Node` 引用。
+// 竞技场活多久，这些引用就有效多久；竞技场被释放时，所有节点一起释放。
+// 生命周期 `'arena` 保证了没有任何引用能活得比竞技场更久。
+//
+// 这里的竞技场完全用安全代码实现：存储空间是一串 `OnceCell<T>` 组成的块，
+// 每个格子只会被写入一次，写入之后就只通过 `&T` 访问，所以可以通过 `&self` 分配新值，
+// 同时把已经分配出去的引用一直保留着。块写满之后再接一个容量翻倍的新块，旧块永远不会移动。
+
+struct Chunk<T> {
+    slots: Box<[OnceCell<T>]>,
+    used: Cell<usize>,
+    next: OnceCell<Box<Chunk<T>>>,
+}
+
+impl<T> Chunk<T> {
+    fn new(capacity: usize) -> Chunk<T> {
+        Chunk {
+            slots: (0..capacity).map(|_| OnceCell::new()).collect(),
+            used: Cell::new(0),
+            next: OnceCell::new(),
+        }
+    }
+}
+
+/// 竞技场分配出来的引用受竞技场的生命周期约束。下面每个错误写法后面都跟着一个只改了一处的正确写法，
+/// 保证错误写法确实是因为注释里说的原因才无法编译。
+///
+/// 引用不能比竞技场活得更久：
+///
+/// ```compile_fail,E0597
+/// use rust_lessons::advanced::arena::{Arena, Graph};
+///
+/// let dangling;
+/// {
+///     let arena = Arena::new();
+///     dangling = Graph::new(&arena).add("temporary");
+/// } // `arena` 在这里被释放，但 `dangling` 仍然借用着它
+/// println!("{}", dangling.label());
+/// ```
+///
+/// ```
+/// use rust_lessons::advanced::arena::{Arena, Graph};
+///
+/// let arena = Arena::new();
+/// let dangling;
+/// {
+///     dangling = Graph::new(&arena).add("temporary");
+/// }
+/// println!("{}", dangling.label());
+/// ```
+///
+/// 竞技场被借用的时候不能移动，否则已经分配的值的地址就变了：
+///
+/// ```compile_fail,E0505
+/// use rust_lessons::advanced::arena::Arena;
+///
+/// let arena = Arena::new();
+/// let hello = arena.alloc(String::from("hello"));
+/// let moved = arena;
+/// println!("{} {}", hello, moved.len());
+/// ```
+///
+/// ```
+/// use rust_lessons::advanced::arena::Arena;
+///
+/// let arena = Arena::new();
+/// let hello = arena.alloc(String::from("hello"));
+/// println!("{}", hello);
+/// let moved = arena;
+/// println!("{}", moved.len());
+/// ```
+///
+/// 分配出来的只有共享引用，不能通过它修改值：
+///
+/// ```compile_fail,E0596
+/// use rust_lessons::advanced::arena::Arena;
+///
+/// let arena = Arena::new();
+/// let hello = arena.alloc(String::from("hello"));
+/// hello.push('!');
+/// ```
+///
+/// ```
+/// use std::cell::RefCell;
+/// use rust_lessons::advanced::arena::Arena;
+///
+/// let arena = Arena::new();
+/// let hello = arena.alloc(RefCell::new(String::from("hello")));
+/// hello.borrow_mut().push('!');
+/// ```
+pub struct Arena<T> {
+    first: Chunk<T>,
+    len: Cell<usize>,
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Arena::new()
+    }
+}
+
+impl<T> Arena<T> {
+    pub fn new() -> Arena<T> {
+        Arena::with_capacity(8)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Arena<T> {
+        Arena { first: Chunk::new(capacity.max(1)), len: Cell::new(0) }
+    }
+
+    // 只需要 `&self`：分配不会让已经拿到的引用失效。
+    // 返回的引用和 `&self` 的生命周期相同，也就是说不会比竞技场活得更久
+    pub fn alloc(&self, value: T) -> &T {
+        let mut chunk = &self.first;
+        loop {
+            let index = chunk.used.get();
+            if index < chunk.slots.len() {
+                chunk.used.set(index + 1);
+                self.len.set(self.len.get() + 1);
+                return chunk.slots[index].get_or_init(|| value);
+            }
+            chunk = chunk.next.get_or_init(|| Box::new(Chunk::new(chunk.slots.len() * 2)));
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len.get()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // 已经分配的块的个数，容量每次翻倍，所以块数只按对数增长
+    pub fn chunk_count(&self) -> usize {
+        let mut count = 1;
+        let mut chunk = &self.first;
+        while let Some(next) = chunk.next.get() {
+            count += 1;
+            chunk = next;
+        }
+        count
+    }
+
+    // 按分配的顺序遍历
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        std::iter::successors(Some(&self.first), |chunk| chunk.next.get().map(|next| &**next))
+            .flat_map(|chunk| chunk.slots.iter().map_while(OnceCell::get))
+    }
+}
+
+// 图的节点。边是指向同一个竞技场中其他节点的引用，
+// 因为节点只能通过 `&` 访问，添加边需要 RefCell 提供的内部可变性
+pub struct Node<'a> {
+    id: usize,
+    label: String,
+    edges: RefCell<Vec<&'a Node<'a>>>,
+}
+
+impl<'a> Node<'a> {
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// 有向边 self -> other。
+    ///
+    /// 不同竞技场的节点也能相连，但长寿的竞技场里的节点不能指向短命的竞技场里的节点：
+    ///
+    /// ```compile_fail,E0597
+    /// use rust_lessons::advanced::arena::{Arena, Graph};
+    ///
+    /// let long_lived = Arena::new();
+    /// let root = Graph::new(&long_lived).add("root");
+    /// {
+    ///     let short_lived = Arena::new();
+    ///     root.connect(Graph::new(&short_lived).add("leaf"));
+    /// } // `short_lived` 被释放了，但 `root` 的边仍然指向它的节点
+    /// println!("{:?}", root);
+    /// ```
+    ///
+    /// ```
+    /// use rust_lessons::advanced::arena::{Arena, Graph};
+    ///
+    /// let long_lived = Arena::new();
+    /// let root = Graph::new(&long_lived).add("root");
+    /// {
+    ///     root.connect(Graph::new(&long_lived).add("leaf"));
+    /// }
+    /// println!("{:?}", root);
+    /// ```
+    pub fn connect(&self, other: &'a Node<'a>) {
+        self.edges.borrow_mut().push(other);
+    }
+
+    // 复制一份边的列表再返回，遍历时就不会一直持有 RefCell 的借用
+    pub fn neighbors(&self) -> Vec<&'a Node<'a>> {
+        self.edges.borrow().clone()
+    }
+}
+
+// 自动派生的 Debug 会顺着边一直打印下去，遇到环就永远停不下来，
+// 所以这里只打印相邻节点的名字
+impl fmt::Debug for Node<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let edges: Vec<&str> = self.edges.borrow().iter().map(|node| node.label()).collect();
+        f.debug_struct("Node").field("id", &self.id).field("label", &self.label).field("edges", &edges).finish()
+    }
+}
+
+pub struct Graph<'a> {
+    arena: &'a Arena<Node<'a>>,
+}
+
+impl<'a> Graph<'a> {
+    pub fn new(arena: &'a Arena<Node<'a>>) -> Graph<'a> {
+        Graph { arena }
+    }
+
+    pub fn add(&self, label: &str) -> &'a Node<'a> {
+        let id = self.arena.len();
+        self.arena.alloc(Node { id, label: label.to_string(), edges: RefCell::new(Vec::new()) })
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &'a Node<'a>> {
+        self.arena.iter()
+    }
+
+    pub fn find(&self, label: &str) -> Option<&'a Node<'a>> {
+        self.nodes().find(|node| node.label() == label)
+    }
+
+    // 从任意一个节点出发，沿着边能不能回到走过的路径上
+    pub fn has_cycle(&self) -> bool {
+        let mut finished = HashSet::new();
+        self.nodes().any(|node| cycle_from(node, &mut Vec::new(), &mut finished))
+    }
+}
+
+fn cycle_from(node: &Node, path: &mut Vec<usize>, finished: &mut HashSet<usize>) -> bool {
+    if path.contains(&node.id) {
+        return true;
+    }
+    if !finished.insert(node.id) {
+        return false;
+    }
+    path.push(node.id);
+    let found = node.neighbors().into_iter().any(|next| cycle_from(next, path, finished));
+    path.pop();
+    found
+}
+
+// 广度优先：按离起点的距离一层一层访问，每个节点只访问一次
+pub fn bfs<'a>(start: &'a Node<'a>) -> Vec<&'a Node<'a>> {
+    let mut visited = HashSet::from([start.id]);
+    let mut queue = VecDeque::from([start]);
+    let mut order = Vec::new();
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+        for next in node.neighbors() {
+            if visited.insert(next.id) {
+                queue.push_back(next);
+            }
+        }
+    }
+    order
+}
+
+// 深度优先（前序），同样靠 visited 集合避免在环里打转
+pub fn dfs<'a>(start: &'a Node<'a>) -> Vec<&'a Node<'a>> {
+    let mut visited = HashSet::new();
+    let mut stack = vec![start];
+    let mut order = Vec::new();
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node.id) {
+            continue;
+        }
+        order.push(node);
+        // 倒着压栈，这样先添加的边先被访问
+        stack.extend(node.neighbors().into_iter().rev());
+    }
+    order
+}
+
+// 边数最少的路径，返回的节点引用都借用自竞技场
+pub fn shortest_path<'a>(from: &'a Node<'a>, to: &Node) -> Option<Vec<&'a Node<'a>>> {
+    let mut previous: Vec<(usize, &'a Node<'a>)> = Vec::new();
+    let mut visited = HashSet::from([from.id]);
+    let mut queue = VecDeque::from([from]);
+    while let Some(node) = queue.pop_front() {
+        if node.id == to.id {
+            let mut path = vec![node];
+            let mut current = node.id;
+            while let Some(&(_, parent)) = previous.iter().find(|(id, _)| *id == current) {
+                path.push(parent);
+                current = parent.id;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for next in node.neighbors() {
+            if visited.insert(next.id) {
+                previous.push((next.id, node));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+// 把从 start 出发能到达的部分画成一棵树。
+// 回到当前路径上的节点标记为 (cycle)，在别的分支已经展开过的节点标记为 (seen)，都不再展开
+pub fn render(start: &Node) -> String {
+    let mut out = format!("{}\n", start.label());
+    let mut expanded = HashSet::from([start.id]);
+    render_children(start, "", &mut vec![start.id], &mut expanded, &mut out);
+    out
+}
+
+fn render_children(node: &Node, prefix: &str, path: &mut Vec<usize>, expanded: &mut HashSet<usize>, out: &mut String) {
+    let children = node.neighbors();
+    for (i, child) in children.iter().enumerate() {
+        let last = i + 1 == children.len();
+        let (branch, indent) = if last { ("└── ", "    ") } else { ("├── ", "│   ") };
+        out.push_str(prefix);
+        out.push_str(branch);
+        out.push_str(child.label());
+        if path.contains(&child.id) {
+            out.push_str(" (cycle)\n");
+        } else if !expanded.insert(child.id) {
+            out.push_str(" (seen)\n");
+        } else {
+            out.push('\n');
+            path.push(child.id);
+            render_children(child, &format!("{}{}", prefix, indent), path, expanded, out);
+            path.pop();
+        }
+    }
+}
+
+fn labels(nodes: &[&Node]) -> Vec<String> {
+    nodes.iter().map(|node| node.label().to_string()).collect()
+}
+
+pub fn arena_example() {
+    // 竞技场本身：分配只需要 `&self`，之前拿到的引用一直有效
+    let words: Arena<String> = Arena::with_capacity(2);
+    let hello = words.alloc(String::from("hello"));
+    let world = words.alloc(String::from("world"));
+    for i in 0..10 {
+        words.alloc(format!("word{}", i));
+    }
+    // 2 + 4 + 8 个格子，前两个引用在新块分配之后依然有效
+    println!("{} {}: {} words in {} chunks", hello, world, words.len(), words.chunk_count());
+
+    // 用竞技场建一张有环的图
+    //
+    //   main ──> parse ──> lex
+    //    │  └──> eval ──┐   │
+    //    │        ▲     ▼   ▼
+    //    │        └── apply  error
+    //    └──────────────────┘
+    let arena = Arena::new();
+    let graph = Graph::new(&arena);
+    let main = graph.add("main");
+    let parse = graph.add("parse");
+    let lex = graph.add("lex");
+    let eval = graph.add("eval");
+    let apply = graph.add("apply");
+    let error = graph.add("error");
+    main.connect(parse);
+    main.connect(eval);
+    main.connect(error);
+    parse.connect(lex);
+    lex.connect(error);
+    eval.connect(apply);
+    apply.connect(eval);
+    println!("{:?}", main);
+
+    println!("bfs: {:?}", labels(&bfs(main)));
+    println!("dfs: {:?}", labels(&dfs(main)));
+    if let Some(path) = shortest_path(parse, error) {
+        println!("parse -> error: {:?}", labels(&path));
+    }
+    println!("eval -> main: {:?}", shortest_path(eval, main).map(|path| labels(&path)));
+
+    // 遇到环也能停下来的打印
+    print!("{}", render(main));
+    println!("has cycle: {}", graph.has_cycle());
+
+    // 自环
+    let dag_arena = Arena::new();
+    let dag = Graph::new(&dag_arena);
+    let a = dag.add("a");
+    let b = dag.add("b");
+    a.connect(b);
+    println!("a -> b has cycle: {}", dag.has_cycle());
+    b.connect(b);
+    print!("{}", render(a));
+
+    // 下面这些错误写法都无法编译。`Arena` 和 `Node::connect` 的文档测试（`compile_fail`）
+    // 会在 `cargo test` 时确认它们仍然被编译器拒绝。
+
+    // 节点的引用不能比竞技场活得更久
+    // let dangling;
+    // {
+    //     let arena = Arena::new();
+    //     dangling = Graph::new(&arena).add("temporary");
+    // } // 错误！`arena` 在这里被释放，但 `dangling` 仍然借用着它
+    // println!("{}", dangling.label());
+
+    // 竞技场被借用的时候也不能移动它，否则节点的地址就变了
+    // let moved = arena; // 错误！不能移出 `arena`，因为它正被 `main` 等节点借用
+    // println!("{}", main.label());
+
+    // 分配出来的只有共享引用，修改节点只能通过 RefCell 这样的内部可变性
+    // main.label = String::from("start"); // 错误！不能通过 `&` 引用修改
+
+    // 不同竞技场的节点也能相连，但生命周期会取两者中较短的那个：
+    // 长寿的竞技场里的节点不能指向短命的竞技场里的节点
+    // let long_lived = Arena::new();
+    // let root = Graph::new(&long_lived).add("root");
+    // {
+    //     let short_lived = Arena::new();
+    //     root.connect(Graph::new(&short_lived).add("leaf"));
+    // } // 错误！`short_lived` 被释放了，但 `root` 的边仍然指向它的节点
+    // println!("{:?}", root);
+}
+
+// 课程注册，见 lesson.rs
+pub struct ArenaLesson;
+
+impl crate::lesson::Lesson for ArenaLesson {
+    fn title(&self) -> &'static str {
+        "Arena Allocation"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("arena.rs")
+    }
+
+    fn run(&self) {
+        arena_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alloc_keeps_earlier_references_valid() {
+        let words: Arena<String> = Arena::with_capacity(2);
+        assert!(words.is_empty());
+        let hello = words.alloc(String::from("hello"));
+        let world = words.alloc(String::from("world"));
+        let more: Vec<&String> = (0..10).map(|i| words.alloc(format!("word{}", i))).collect();
+        assert_eq!((hello.as_str(), world.as_str()), ("hello", "world"));
+        assert_eq!(more[9], "word9");
+        assert_eq!(words.len(), 12);
+        // 2 + 4 + 8 个格子
+        assert_eq!(words.chunk_count(), 3);
+        assert_eq!(words.iter().count(), 12);
+        assert_eq!(words.iter().next(), Some(hello));
+        assert_eq!(Arena::<i32>::default().iter().count(), 0);
+    }
+
+    #[test]
+    fn traversals_on_cyclic_graph() {
+        let arena = Arena::new();
+        let graph = Graph::new(&arena);
+        let main = graph.add("main");
+        let parse = graph.add("parse");
+        let lex = graph.add("lex");
+        let eval = graph.add("eval");
+        let apply = graph.add("apply");
+        let error = graph.add("error");
+        main.connect(parse);
+        main.connect(eval);
+        main.connect(error);
+        parse.connect(lex);
+        lex.connect(error);
+        eval.connect(apply);
+        apply.connect(eval);
+        assert_eq!(arena.len(), 6);
+        assert_eq!(graph.find("lex").map(|node| node.id()), Some(2));
+        assert!(graph.find("missing").is_none());
+
+        assert_eq!(labels(&bfs(main)), vec!["main", "parse", "eval", "error", "lex", "apply"]);
+        assert_eq!(labels(&dfs(main)), vec!["main", "parse", "lex", "error", "eval", "apply"]);
+        assert_eq!(labels(&bfs(apply)), vec!["apply", "eval"]);
+
+        assert_eq!(labels(&shortest_path(main, error).unwrap()), vec!["main", "error"]);
+        assert_eq!(labels(&shortest_path(parse, error).unwrap()), vec!["parse", "lex", "error"]);
+        assert!(shortest_path(eval, main).is_none());
+        assert_eq!(labels(&shortest_path(lex, lex).unwrap()), vec!["lex"]);
+
+        assert_eq!(
+            render(main),
+            "main\n\
+             ├── parse\n\
+             │   └── lex\n\
+             │       └── error\n\
+             ├── eval\n\
+             │   └── apply\n\
+             │       └── eval (cycle)\n\
+             └── error (seen)\n"
+        );
+        assert!(graph.has_cycle());
+        assert_eq!(format!("{:?}", main), r#"Node { id: 0, label: "main", edges: ["parse", "eval", "error"] }"#);
+    }
+
+    #[test]
+    fn self_loop_is_a_cycle() {
+        let arena = Arena::new();
+        let graph = Graph::new(&arena);
+        let a = graph.add("a");
+        let b = graph.add("b");
+        let c = graph.add("c");
+        a.connect(b);
+        a.connect(c);
+        b.connect(c);
+        assert!(!graph.has_cycle());
+        c.connect(c);
+        assert!(graph.has_cycle());
+        assert_eq!(render(c), "c\n└── c (cycle)\n");
+    }
+}
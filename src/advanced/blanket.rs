@@ -0,0 +1,210 @@
+use std::fmt;
+
+use super::traits;
+
+// 超特征与覆盖实现（Supertraits and Blanket Impls）
+// `traits.rs` 为每个类型单独实现 `Summary`。这里换一种做法：
+// 只要一个类型实现了 `Display`，就自动实现这个模块里的 `Summary`——这叫覆盖实现（blanket impl），
+// 标准库里的 `impl<T: Display> ToString for T` 就是这样来的。
+// 同名的两个特征放在不同的模块里，正好用来观察方法解析和一致性（coherence）规则。
+
+pub trait Summary {
+    fn summarize_author(&self) -> String;
+
+    fn summarize(&self) -> String {
+        format!("(Read more from {}...)", self.summarize_author())
+    }
+}
+
+// 覆盖实现：所有实现了 Display 的类型，包括 i32、String、str 和以后别人定义的类型。
+// `?Sized` 让 str 这样的非定长类型也能用上
+impl<T: fmt::Display + ?Sized> Summary for T {
+    fn summarize_author(&self) -> String {
+        self.to_string()
+    }
+}
+
+// 超特征：实现 OutlinePrint 的类型必须先实现 Display，
+// 所以默认方法里可以放心地调用 `to_string()`
+pub trait OutlinePrint: fmt::Display {
+    fn outline(&self) -> String {
+        let text = self.to_string();
+        let width = text.chars().count();
+        let border = "*".repeat(width + 4);
+        format!("{}\n* {} *\n{}", border, text, border)
+    }
+}
+
+// 超特征可以有多个：同时要求 Display 和 traits::Summary，默认方法两边的方法都能用
+pub trait Headline: fmt::Display + traits::Summary {
+    fn headline(&self) -> String {
+        format!("{} | {}", self, self.summarize_author())
+    }
+}
+
+pub struct Channel {
+    pub name: String,
+    pub subscribers: u32,
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{}", self.name)
+    }
+}
+
+// Channel 实现了 Display，所以已经通过覆盖实现拥有了 blanket::Summary；
+// 它还可以再实现 traits::Summary，这是两个不同的特征，互不冲突
+impl traits::Summary for Channel {
+    fn summarize_author(&self) -> String {
+        format!("{} subscribers", self.subscribers)
+    }
+}
+
+impl OutlinePrint for Channel {}
+
+impl Headline for Channel {}
+
+impl Channel {
+    // 固有方法（inherent method）和特征方法同名时，`channel.summarize()` 优先调用固有方法
+    pub fn summarize(&self) -> String {
+        format!("channel {}", self.name)
+    }
+}
+
+// 本地类型即使没有实现 Display，也可以手动实现 Summary：
+// 编译器知道 Display 只能由本 crate 为 Silent 实现，所以可以确定两个实现不会重叠。
+// 一旦给 Silent 加上 Display，这个手写的实现就会和覆盖实现冲突
+pub struct Silent;
+
+impl Summary for Silent {
+    fn summarize_author(&self) -> String {
+        String::from("nobody")
+    }
+}
+
+// 孤儿规则：不能为外部类型实现外部特征，比如为 Vec<String> 实现 Display。
+// 用一个本地的新类型包一层就可以，然后它自动得到覆盖实现的 Summary
+pub struct Names(pub Vec<String>);
+
+impl fmt::Display for Names {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}]", self.0.join(", "))
+    }
+}
+
+// 泛型函数只需要一个约束，就能接受所有实现了 Display 的类型
+fn preview<T: Summary + ?Sized>(item: &T) -> String {
+    item.summarize()
+}
+
+pub fn blanket_example() {
+    // 覆盖实现让标准库的类型也有了 summarize
+    println!("{}", 42.summarize());
+    println!("{}", String::from("crab").summarize());
+    println!("{}", preview("str 是非定长类型"));
+    println!("{}", preview(&Names(vec![String::from("Ann"), String::from("Bo")])));
+    println!("{}", Silent.summarize());
+
+    // 方法解析：固有方法优先
+    let channel = Channel { name: String::from("rust"), subscribers: 1200 };
+    println!("{}", channel.summarize());
+    // 特征方法要用完全限定语法指明是哪一个
+    println!("{}", <Channel as Summary>::summarize(&channel));
+    println!("{}", <Channel as traits::Summary>::summarize(&channel));
+    // traits::Summary 没有用 `use` 导入，所以点号调用只会找到本模块的 Summary
+    println!("{}", channel.summarize_author());
+    // 两个特征都导入之后，点号调用就有歧义了
+    // use traits::Summary as _;
+    // channel.summarize_author(); // 错误！多个适用的方法（E0034）
+
+    // 特征对象也走同样的规则：通过 &dyn traits::Summary 只能看到那个特征的方法
+    let as_object: &dyn traits::Summary = &channel;
+    println!("{}", as_object.summarize_author());
+
+    // 超特征
+    println!("{}", channel.outline());
+    println!("{}", channel.headline());
+
+    // 一致性规则：编译器拒绝的写法
+    // impl Summary for i32 {
+    //     fn summarize_author(&self) -> String { String::new() }
+    // } // 错误！与 `impl<T: Display> Summary for T` 冲突，i32 已经实现了 Display（E0119）
+    //
+    // impl fmt::Display for Vec<String> { ... }
+    // // 错误！孤儿规则：Display 和 Vec 都不是这个 crate 定义的（E0117）
+    //
+    // impl OutlinePrint for Silent {}
+    // // 错误！Silent 没有实现超特征 Display（E0277）
+}
+
+// 课程注册，见 lesson.rs
+pub struct BlanketLesson;
+
+impl crate::lesson::Lesson for BlanketLesson {
+    fn title(&self) -> &'static str {
+        "Supertraits and Blanket Impls"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("blanket.rs")
+    }
+
+    fn run(&self) {
+        blanket_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 覆盖实现让标准库的类型也有了 summarize
+    #[test]
+    fn blanket_impl_covers_display_types() {
+        assert_eq!(42.summarize(), "(Read more from 42...)");
+        assert_eq!("ferris".summarize_author(), "ferris");
+        assert_eq!(String::from("crab").summarize(), "(Read more from crab...)");
+        assert_eq!(3.5.summarize_author(), "3.5");
+        assert_eq!(preview("str 是非定长类型"), "(Read more from str 是非定长类型...)");
+        let names = Names(vec![String::from("Ann"), String::from("Bo")]);
+        assert_eq!(preview(&names), "(Read more from [Ann, Bo]...)");
+    }
+
+    // 没有实现 Display 的本地类型可以手写实现
+    #[test]
+    fn handwritten_impl_for_non_display_type() {
+        assert_eq!(Silent.summarize(), "(Read more from nobody...)");
+    }
+
+    // 固有方法优先，特征方法要用完全限定语法区分
+    #[test]
+    fn method_resolution() {
+        let channel = Channel { name: String::from("rust"), subscribers: 1200 };
+        assert_eq!(channel.summarize(), "channel rust");
+        assert_eq!(<Channel as Summary>::summarize(&channel), "(Read more from #rust...)");
+        assert_eq!(<Channel as traits::Summary>::summarize(&channel), "(Read more from 1200 subscribers...)");
+        assert_eq!(Summary::summarize_author(&channel), "#rust");
+        assert_eq!(traits::Summary::summarize_author(&channel), "1200 subscribers");
+        assert_eq!(channel.summarize_author(), "#rust");
+
+        // 通过 &dyn traits::Summary 只能看到那个特征的方法
+        let as_object: &dyn traits::Summary = &channel;
+        assert_eq!(as_object.summarize_author(), "1200 subscribers");
+
+        // 自动解引用：&&Channel 也能找到 Display，进而找到覆盖实现
+        let reference = &&channel;
+        assert_eq!(Summary::summarize_author(reference), "#rust");
+    }
+
+    #[test]
+    fn supertraits() {
+        let channel = Channel { name: String::from("rust"), subscribers: 1200 };
+        assert_eq!(channel.outline(), "*********\n* #rust *\n*********");
+        assert_eq!(channel.headline(), "#rust | 1200 subscribers");
+    }
+}
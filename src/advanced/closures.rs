@@ -68,4 +68,25 @@ pub fn closures_example() {
     let v1 = vec![1, 2, 3];
     let v2: Vec<_> = v1.iter().map(|x| x + 1).collect();
     println!("v2: {:?}", v2);
-}
\ No newline at end of file
+}
+
+// 课程注册，见 lesson.rs
+pub struct ClosuresLesson;
+
+impl crate::lesson::Lesson for ClosuresLesson {
+    fn title(&self) -> &'static str {
+        "Closures"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("closures.rs")
+    }
+
+    fn run(&self) {
+        closures_example()
+    }
+}
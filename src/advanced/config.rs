@@ -0,0 +1,482 @@
+use std::collections::HashSet;
+use std::fmt;
+
+// 借用输入的配置解析器（Zero-Copy Config Parser）
+// `ImportantExcerpt` 只借用了一句话。真实程序里借用最常见的用途是解析：
+// 配置文件读进一个 String 之后，解析结果里的每个键和值都可以直接是这个 String 的切片，
+// 不需要为成千上万个键值对各分配一次内存。`Config<'a>` 就是这样一个解析器，
+// 生命周期 `'a` 表示“解析结果借用自原文”，原文必须比解析结果活得更久。
+// 需要让配置脱离原文单独存在时，再用 `into_owned()` 一次性复制出来。
+//
+// 支持的格式（INI 的常见子集）：
+//
+//     ; 注释，# 开头也可以
+//     name = demo          <- 第一个小节之前的键属于全局小节（名字为空）
+//     [server]             ; 小节名和值后面都可以跟注释
+//     host = 127.0.0.1     ; 行内注释以空白后的 ; 或 # 开始，不属于值
+//     motd = "  前后有空格的值要加引号  "
+//     url = "a;b#c"        <- 引号里的 ; 和 # 是值的一部分
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigErrorKind {
+    UnclosedSection,
+    EmptySectionName,
+    // `]` 或者引号值的右引号后面还有注释以外的内容
+    TrailingCharacters,
+    DuplicateSection(String),
+    MissingEquals,
+    EmptyKey,
+    InvalidKeyChar(char),
+    DuplicateKey(String),
+    UnclosedQuote,
+}
+
+// 行号和列号都从 1 开始，列号按字符计数，和编辑器里看到的一致
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ConfigErrorKind,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match &self.kind {
+            ConfigErrorKind::UnclosedSection => write!(f, "section header is missing `]`"),
+            ConfigErrorKind::EmptySectionName => write!(f, "section name is empty"),
+            ConfigErrorKind::TrailingCharacters => write!(f, "unexpected characters after section header"),
+            ConfigErrorKind::DuplicateSection(name) => write!(f, "section [{}] is defined twice", name),
+            ConfigErrorKind::MissingEquals => write!(f, "expected `key = value`"),
+            ConfigErrorKind::EmptyKey => write!(f, "key is empty"),
+            ConfigErrorKind::InvalidKeyChar(c) => write!(f, "invalid character {:?} in key", c),
+            ConfigErrorKind::DuplicateKey(key) => write!(f, "key `{}` is defined twice in this section", key),
+            ConfigErrorKind::UnclosedQuote => write!(f, "quoted value is missing the closing `\"`"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    name: &'a str,
+    entries: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Section<'a> {
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        self.entries.iter().find(|(k, _)| *k == key).map(|&(_, v)| v)
+    }
+
+    // 按文件中的顺序
+    pub fn entries(&self) -> impl Iterator<Item = (&'a str, &'a str)> + '_ {
+        self.entries.iter().copied()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config<'a> {
+    // 第一个总是全局小节
+    sections: Vec<Section<'a>>,
+}
+
+impl<'a> Config<'a> {
+    pub fn parse(text: &'a str) -> Result<Config<'a>, ConfigError> {
+        let mut sections = vec![Section { name: "", entries: Vec::new() }];
+        // 查重用 HashSet：逐个比较已有的小节和键会让解析变成 O(n²)
+        let mut seen_sections: HashSet<&str> = HashSet::from([""]);
+        let mut seen_keys: HashSet<&str> = HashSet::new();
+        for (index, raw) in text.lines().enumerate() {
+            let line = index + 1;
+            match parse_line(raw, line)? {
+                Line::Blank => {}
+                Line::Section { name, column } => {
+                    if !seen_sections.insert(name) {
+                        let kind = ConfigErrorKind::DuplicateSection(name.to_string());
+                        return Err(ConfigError { line, column, kind });
+                    }
+                    seen_keys.clear();
+                    sections.push(Section { name, entries: Vec::new() });
+                }
+                Line::Entry { key, value, column } => {
+                    if !seen_keys.insert(key) {
+                        let kind = ConfigErrorKind::DuplicateKey(key.to_string());
+                        return Err(ConfigError { line, column, kind });
+                    }
+                    sections.last_mut().unwrap().entries.push((key, value));
+                }
+            }
+        }
+        Ok(Config { sections })
+    }
+
+    // 全局小节用 "" 表示
+    pub fn section(&self, name: &str) -> Option<&Section<'a>> {
+        self.sections.iter().find(|s| s.name == name)
+    }
+
+    // 返回值借用的是原文而不是 `self`，所以可以比 Config 活得更久
+    pub fn get(&self, section: &str, key: &str) -> Option<&'a str> {
+        self.section(section)?.get(key)
+    }
+
+    pub fn sections(&self) -> impl Iterator<Item = &Section<'a>> {
+        self.sections.iter()
+    }
+
+    // 复制出所有的字符串，结果不再借用原文
+    pub fn into_owned(self) -> OwnedConfig {
+        let sections = self
+            .sections
+            .into_iter()
+            .map(|section| OwnedSection {
+                name: section.name.to_string(),
+                entries: section.entries.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            })
+            .collect();
+        OwnedConfig { sections }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedSection {
+    name: String,
+    entries: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedConfig {
+    sections: Vec<OwnedSection>,
+}
+
+impl OwnedConfig {
+    // 分配 String 的版本：每个键和值都复制一份。用来和 `Config::parse` 做对比
+    pub fn parse(text: &str) -> Result<OwnedConfig, ConfigError> {
+        let mut sections = vec![OwnedSection { name: String::new(), entries: Vec::new() }];
+        // 和借用版本一样的查重方式，这样性能对比只反映复制字符串的开销
+        let mut seen_sections: HashSet<&str> = HashSet::from([""]);
+        let mut seen_keys: HashSet<&str> = HashSet::new();
+        for (index, raw) in text.lines().enumerate() {
+            let line = index + 1;
+            match parse_line(raw, line)? {
+                Line::Blank => {}
+                Line::Section { name, column } => {
+                    if !seen_sections.insert(name) {
+                        let kind = ConfigErrorKind::DuplicateSection(name.to_string());
+                        return Err(ConfigError { line, column, kind });
+                    }
+                    seen_keys.clear();
+                    sections.push(OwnedSection { name: name.to_string(), entries: Vec::new() });
+                }
+                Line::Entry { key, value, column } => {
+                    if !seen_keys.insert(key) {
+                        let kind = ConfigErrorKind::DuplicateKey(key.to_string());
+                        return Err(ConfigError { line, column, kind });
+                    }
+                    sections.last_mut().unwrap().entries.push((key.to_string(), value.to_string()));
+                }
+            }
+        }
+        Ok(OwnedConfig { sections })
+    }
+
+    pub fn get(&self, section: &str, key: &str) -> Option<&str> {
+        let section = self.sections.iter().find(|s| s.name == section)?;
+        section.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    pub fn section_names(&self) -> impl Iterator<Item = &str> {
+        self.sections.iter().map(|s| s.name.as_str())
+    }
+}
+
+// 单独一行的解析结果，两个解析器共用。列号指向出问题或者值得报告的位置
+enum Line<'a> {
+    Blank,
+    Section { name: &'a str, column: usize },
+    Entry { key: &'a str, value: &'a str, column: usize },
+}
+
+// 字节偏移转换成从 1 开始的字符列号
+fn column_at(raw: &str, byte: usize) -> usize {
+    raw[..byte].chars().count() + 1
+}
+
+// `part` 必须是 `raw` 的切片，返回它在 `raw` 中的字节偏移
+fn offset_in(raw: &str, part: &str) -> usize {
+    part.as_ptr() as usize - raw.as_ptr() as usize
+}
+
+fn is_comment(text: &str) -> bool {
+    text.starts_with(';') || text.starts_with('#')
+}
+
+// 不带引号的值：空白后面的 ; 或 # 开始一段注释，`a#b` 这样紧挨着的不算
+fn strip_inline_comment(value: &str) -> &str {
+    let mut previous = ' ';
+    for (i, c) in value.char_indices() {
+        if (c == ';' || c == '#') && previous.is_whitespace() {
+            return value[..i].trim_end();
+        }
+        previous = c;
+    }
+    value
+}
+
+fn parse_line(raw: &str, line: usize) -> Result<Line<'_>, ConfigError> {
+    let error = |byte: usize, kind| ConfigError { line, column: column_at(raw, byte), kind };
+    let trimmed = raw.trim();
+    if trimmed.is_empty() || is_comment(trimmed) {
+        return Ok(Line::Blank);
+    }
+    let start = offset_in(raw, trimmed);
+
+    if let Some(header) = trimmed.strip_prefix('[') {
+        let Some(close) = header.find(']') else {
+            return Err(error(start + trimmed.len(), ConfigErrorKind::UnclosedSection));
+        };
+        let after = header[close + 1..].trim_start();
+        if !after.is_empty() && !is_comment(after) {
+            return Err(error(offset_in(raw, after), ConfigErrorKind::TrailingCharacters));
+        }
+        let name = header[..close].trim();
+        if name.is_empty() {
+            return Err(error(start, ConfigErrorKind::EmptySectionName));
+        }
+        return Ok(Line::Section { name, column: column_at(raw, offset_in(raw, name)) });
+    }
+
+    let Some(equals) = trimmed.find('=') else {
+        return Err(error(start + trimmed.len(), ConfigErrorKind::MissingEquals));
+    };
+    let key = trimmed[..equals].trim_end();
+    if key.is_empty() {
+        return Err(error(start, ConfigErrorKind::EmptyKey));
+    }
+    if let Some((i, c)) = key.char_indices().find(|&(_, c)| !(c.is_alphanumeric() || "_-.".contains(c))) {
+        return Err(error(start + i, ConfigErrorKind::InvalidKeyChar(c)));
+    }
+
+    // 引号值在第一个右引号处结束，后面只能跟注释，和小节名的 `]` 后面一样
+    let rest = trimmed[equals + 1..].trim_start();
+    let value = match rest.strip_prefix('"') {
+        Some(quoted) => {
+            let Some(close) = quoted.find('"') else {
+                return Err(error(offset_in(raw, rest), ConfigErrorKind::UnclosedQuote));
+            };
+            let after = quoted[close + 1..].trim_start();
+            if !after.is_empty() && !is_comment(after) {
+                return Err(error(offset_in(raw, after), ConfigErrorKind::TrailingCharacters));
+            }
+            &quoted[..close]
+        }
+        None => strip_inline_comment(rest),
+    };
+    Ok(Line::Entry { key, value, column: column_at(raw, start) })
+}
+
+// 生成一份有很多小节的配置，用来比较两种解析器。
+// 单元测试用它核对两者的结果，`benches/config.rs` 用它比较两者的耗时
+pub fn generate(sections: usize, keys: usize) -> String {
+    let mut text = String::from("; generated\nname = benchmark\n");
+    for s in 0..sections {
+        text.push_str(&format!("\n[service.{}]\n", s));
+        for k in 0..keys {
+            text.push_str(&format!("key_{} = value number {} of service {}\n", k, k, s));
+        }
+    }
+    text
+}
+
+const SAMPLE: &str = "; 全局设置\n\
+     name = demo\n\
+     \n\
+     [server]\n\
+     host = 127.0.0.1\n\
+     port=8080   \n\
+     motd = \"  欢迎光临  \"\n\
+     \n\
+     [数据库] ; 小节名可以是中文\n\
+     url = postgres://localhost/demo#main ; 紧挨着的 # 属于值\n\
+     pool.size = 16 # 连接数\n\
+     password = \"p;ss #1\" ; 引号里的不是注释\n";
+
+pub fn config_example() {
+    let text = String::from(SAMPLE);
+
+    let config = Config::parse(&text).unwrap();
+    for section in config.sections() {
+        for (key, value) in section.entries() {
+            println!("[{}] {} = {:?}", section.name(), key, value);
+        }
+    }
+    println!("server.missing = {:?}, missing.host = {:?}", config.get("server", "missing"), config.get("missing", "host"));
+
+    // 值借用的是原文，不是 Config：Config 被丢弃之后值仍然可用
+    let host = {
+        let parsed = Config::parse(&text).unwrap();
+        parsed.get("server", "host").unwrap()
+    };
+    println!("host = {}", host);
+
+    // 但原文必须活得比解析结果久
+    // let config;
+    // {
+    //     let text = String::from("a = 1");
+    //     config = Config::parse(&text).unwrap();
+    // } // 错误！`text` 在这里被释放，但 `config` 仍然借用着它
+    // println!("{:?}", config.get("", "a"));
+
+    // into_owned 之后就和原文无关了
+    let owned = {
+        let temporary = String::from("[cache]\nttl = 60\n");
+        Config::parse(&temporary).unwrap().into_owned()
+    };
+    println!("cache.ttl = {:?}, sections = {:?}", owned.get("cache", "ttl"), owned.section_names().collect::<Vec<_>>());
+
+    // 错误带有行号和列号
+    for input in ["[server\n", "[a] extra", "ok = 1\njust a line", "名字 key = 1", "[a]\nx = 1\ny = 2\n  x = 3", "motd = \"unterminated"] {
+        println!("{:?} -> {}", input, Config::parse(input).unwrap_err());
+    }
+
+    // 两种解析器的行为完全一样，区别只在于是否复制键和值。
+    // 借用版本只为每个小节分配一个 Vec，分配 String 的版本为每个键和值各分配一次；
+    // 耗时的对比见 `cargo bench --bench config`
+    let big = generate(100, 20);
+    let borrowed = Config::parse(&big).unwrap();
+    let copied = OwnedConfig::parse(&big).unwrap();
+    println!(
+        "{} KiB: borrowed {:?}, copied {:?}, same result: {}",
+        big.len() / 1024,
+        borrowed.get("service.99", "key_19"),
+        copied.get("service.99", "key_19"),
+        borrowed.into_owned() == copied
+    );
+}
+
+// 课程注册，见 lesson.rs
+pub struct ConfigLesson;
+
+impl crate::lesson::Lesson for ConfigLesson {
+    fn title(&self) -> &'static str {
+        "Zero-Copy Config Parser"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("config.rs")
+    }
+
+    fn run(&self) {
+        config_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sample() {
+        let config = Config::parse(SAMPLE).unwrap();
+        assert_eq!(config.get("", "name"), Some("demo"));
+        assert_eq!(config.get("server", "port"), Some("8080"));
+        assert_eq!(config.get("server", "motd"), Some("  欢迎光临  "));
+        assert_eq!(config.get("数据库", "pool.size"), Some("16"));
+        assert_eq!(config.get("数据库", "url"), Some("postgres://localhost/demo#main"));
+        assert_eq!(config.get("数据库", "password"), Some("p;ss #1"));
+        assert_eq!(Config::parse("empty = ; 只有注释").unwrap().get("", "empty"), Some(""));
+        assert_eq!(config.get("server", "missing"), None);
+        assert_eq!(config.get("missing", "host"), None);
+        let names: Vec<&str> = config.sections().map(|s| s.name()).collect();
+        assert_eq!(names, vec!["", "server", "数据库"]);
+        let server: Vec<(&str, &str)> = config.section("server").unwrap().entries().collect();
+        assert_eq!(server, vec![("host", "127.0.0.1"), ("port", "8080"), ("motd", "  欢迎光临  ")]);
+    }
+
+    // 零拷贝：每个键和值都指向原文内部
+    #[test]
+    fn values_borrow_from_the_text() {
+        let text = String::from(SAMPLE);
+        let config = Config::parse(&text).unwrap();
+        let range = text.as_bytes().as_ptr_range();
+        for section in config.sections() {
+            for (key, value) in section.entries() {
+                assert!(range.contains(&key.as_ptr()));
+                assert!(value.is_empty() || range.contains(&value.as_ptr()));
+            }
+        }
+    }
+
+    // 值借用的是原文，不是 Config：Config 被丢弃之后值仍然可用
+    #[test]
+    fn values_outlive_the_config() {
+        let text = String::from(SAMPLE);
+        let host = {
+            let parsed = Config::parse(&text).unwrap();
+            parsed.get("server", "host").unwrap()
+        };
+        assert_eq!(host, "127.0.0.1");
+    }
+
+    #[test]
+    fn into_owned_detaches_from_the_text() {
+        let owned = {
+            let temporary = String::from("[cache]\nttl = 60\n");
+            Config::parse(&temporary).unwrap().into_owned()
+        };
+        assert_eq!(owned.get("cache", "ttl"), Some("60"));
+        assert_eq!(owned.section_names().collect::<Vec<_>>(), vec!["", "cache"]);
+        assert_eq!(Config::parse(SAMPLE).unwrap().into_owned(), OwnedConfig::parse(SAMPLE).unwrap());
+    }
+
+    // 错误带有行号和列号，两种解析器报告同样的错误
+    #[test]
+    fn errors_have_positions() {
+        let cases = [
+            ("[server\n", 1, 8, ConfigErrorKind::UnclosedSection),
+            ("  [ ]", 1, 3, ConfigErrorKind::EmptySectionName),
+            ("[a] extra", 1, 5, ConfigErrorKind::TrailingCharacters),
+            ("[a]\n[b]\n [a]", 3, 3, ConfigErrorKind::DuplicateSection(String::from("a"))),
+            ("ok = 1\njust a line", 2, 12, ConfigErrorKind::MissingEquals),
+            ("  = 1", 1, 3, ConfigErrorKind::EmptyKey),
+            ("名字 key = 1", 1, 3, ConfigErrorKind::InvalidKeyChar(' ')),
+            ("[a]\nx = 1\ny = 2\n  x = 3", 4, 3, ConfigErrorKind::DuplicateKey(String::from("x"))),
+            ("motd = \"unterminated", 1, 8, ConfigErrorKind::UnclosedQuote),
+            ("motd = \"abc\" trailing", 1, 14, ConfigErrorKind::TrailingCharacters),
+        ];
+        for (input, line, column, kind) in cases {
+            let error = Config::parse(input).unwrap_err();
+            assert_eq!(error, ConfigError { line, column, kind }, "{:?}", input);
+            assert_eq!(OwnedConfig::parse(input).unwrap_err(), error, "{:?}", input);
+        }
+        assert_eq!(Config::parse("ok = 1\njust a line").unwrap_err().to_string(), "2:12: expected `key = value`");
+    }
+
+    // 同名的键在不同的小节里是允许的
+    #[test]
+    fn same_key_in_different_sections() {
+        let config = Config::parse("[a]\nx = 1\n[b]\nx = 2").unwrap();
+        assert_eq!((config.get("a", "x"), config.get("b", "x")), (Some("1"), Some("2")));
+    }
+
+    // 大输入上两种解析器的结果完全一样
+    #[test]
+    fn parsers_agree_on_generated_input() {
+        let big = generate(100, 20);
+        let borrowed = Config::parse(&big).unwrap();
+        let copied = OwnedConfig::parse(&big).unwrap();
+        assert_eq!(borrowed.get("service.99", "key_19"), Some("value number 19 of service 99"));
+        assert_eq!(borrowed.get("", "name"), copied.get("", "name"));
+        assert_eq!(borrowed.sections().count(), 101);
+        assert_eq!(borrowed.into_owned(), copied);
+    }
+}
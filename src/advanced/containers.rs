@@ -0,0 +1,897 @@
+use std::fmt;
+use std::iter::Rev;
+use std::ops::{Deref, DerefMut};
+use std::slice;
+
+// 泛型容器（Generic Containers）
+// `generics.rs` 中的 `impl<T> Point<T>` 展示了怎样给泛型结构体写方法。
+// 这里用同样的写法实现四个真正的容器：栈、环形缓冲队列、二叉堆和单向链表。
+// 每个容器都提供三种迭代器（`iter` 借用、`iter_mut` 可变借用、`into_iter` 获取所有权），
+// 并实现 `FromIterator`（可以 `collect()`）、`Extend` 和 `Debug`，用法和标准库的容器一致。
+// 只有需要比较元素的二叉堆要求 `T: Ord`，其余容器对 `T` 没有任何约束。
+
+// 栈：后进先出，直接建立在 Vec 上
+// 迭代顺序是从栈顶到栈底，也就是 pop 的顺序，所以三种迭代器都是把 Vec 的迭代器反过来
+pub struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    pub fn new() -> Stack<T> {
+        Stack { items: Vec::new() }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.items.last_mut()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn iter(&self) -> Rev<slice::Iter<'_, T>> {
+        self.items.iter().rev()
+    }
+
+    pub fn iter_mut(&mut self) -> Rev<slice::IterMut<'_, T>> {
+        self.items.iter_mut().rev()
+    }
+}
+
+// `#[derive(Default)]` 会要求 `T: Default`，空栈其实不需要，所以手写
+impl<T> Default for Stack<T> {
+    fn default() -> Stack<T> {
+        Stack::new()
+    }
+}
+
+// 按迭代的顺序依次入栈，最后一个元素在栈顶
+impl<T> FromIterator<T> for Stack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Stack<T> {
+        Stack { items: iter.into_iter().collect() }
+    }
+}
+
+impl<T> Extend<T> for Stack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.items.extend(iter);
+    }
+}
+
+// `for item in stack`
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = Rev<std::vec::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter().rev()
+    }
+}
+
+// `for item in &stack`
+impl<'a, T> IntoIterator for &'a Stack<T> {
+    type Item = &'a T;
+    type IntoIter = Rev<slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// `for item in &mut stack`
+impl<'a, T> IntoIterator for &'a mut Stack<T> {
+    type Item = &'a mut T;
+    type IntoIter = Rev<slice::IterMut<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+// 和迭代顺序一致，栈顶在前
+impl<T: fmt::Debug> fmt::Debug for Stack<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// 队列：先进先出，用环形缓冲区（ring buffer）实现
+// 元素占据 `head` 开始的连续 `len` 个槽位，到了末尾就绕回开头，出队时只需要移动 `head`，
+// 不用像 `Vec::remove(0)` 那样搬动所有元素。缓冲区满了才扩容到两倍。
+// 空槽位用 None 表示，这样不需要 unsafe 就能“留出”未初始化的位置
+pub struct Queue<T> {
+    buffer: Vec<Option<T>>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> Queue<T> {
+    pub fn new() -> Queue<T> {
+        Queue::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Queue<T> {
+        let mut buffer = Vec::with_capacity(capacity);
+        buffer.resize_with(capacity, || None);
+        Queue { buffer, head: 0, len: 0 }
+    }
+
+    pub fn push_back(&mut self, item: T) {
+        if self.len == self.buffer.len() {
+            self.grow();
+        }
+        let tail = (self.head + self.len) % self.buffer.len();
+        self.buffer[tail] = Some(item);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let item = self.buffer[self.head].take();
+        self.head = (self.head + 1) % self.buffer.len();
+        self.len -= 1;
+        item
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.iter().next()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn iter(&self) -> QueueIter<'_, T> {
+        let (front, back) = self.slots();
+        QueueIter { front: front.iter(), back: back.iter() }
+    }
+
+    pub fn iter_mut(&mut self) -> QueueIterMut<'_, T> {
+        let (front, back) = self.slots_mut();
+        QueueIterMut { front: front.iter_mut(), back: back.iter_mut() }
+    }
+
+    // 被占用的槽位分成两段：从 head 到缓冲区末尾，以及绕回开头的部分
+    fn slots(&self) -> (&[Option<T>], &[Option<T>]) {
+        let first_len = self.len.min(self.buffer.len() - self.head);
+        let front = &self.buffer[self.head..self.head + first_len];
+        let back = &self.buffer[..self.len - first_len];
+        (front, back)
+    }
+
+    fn slots_mut(&mut self) -> (&mut [Option<T>], &mut [Option<T>]) {
+        let first_len = self.len.min(self.buffer.len() - self.head);
+        let back_len = self.len - first_len;
+        // 两段不重叠，split_at_mut 让借用检查器也知道这一点
+        let (start, end) = self.buffer.split_at_mut(self.head);
+        (&mut end[..first_len], &mut start[..back_len])
+    }
+
+    // 只在缓冲区满的时候调用：按队列顺序搬到新缓冲区的开头
+    fn grow(&mut self) {
+        let capacity = (self.buffer.len() * 2).max(4);
+        let mut buffer = Vec::with_capacity(capacity);
+        let (start, end) = self.buffer.split_at_mut(self.head);
+        buffer.extend(end.iter_mut().chain(start.iter_mut()).map(Option::take));
+        buffer.resize_with(capacity, || None);
+        self.buffer = buffer;
+        self.head = 0;
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Queue<T> {
+        Queue::new()
+    }
+}
+
+impl<T> FromIterator<T> for Queue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Queue<T> {
+        let mut queue = Queue::new();
+        queue.extend(iter);
+        queue
+    }
+}
+
+impl<T> Extend<T> for Queue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+// 被占用的槽位一定是 Some，所以 `Option::as_ref` 只是把 `&Option<T>` 变成 `&T`
+pub struct QueueIter<'a, T> {
+    front: slice::Iter<'a, Option<T>>,
+    back: slice::Iter<'a, Option<T>>,
+}
+
+impl<'a, T> Iterator for QueueIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.front.next().or_else(|| self.back.next()).and_then(Option::as_ref)
+    }
+}
+
+pub struct QueueIterMut<'a, T> {
+    front: slice::IterMut<'a, Option<T>>,
+    back: slice::IterMut<'a, Option<T>>,
+}
+
+impl<'a, T> Iterator for QueueIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.front.next().or_else(|| self.back.next()).and_then(Option::as_mut)
+    }
+}
+
+// 获取所有权的迭代器就是不断出队
+pub struct QueueIntoIter<T> {
+    queue: Queue<T>,
+}
+
+impl<T> Iterator for QueueIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.queue.pop_front()
+    }
+}
+
+impl<T> IntoIterator for Queue<T> {
+    type Item = T;
+    type IntoIter = QueueIntoIter<T>;
+
+    fn into_iter(self) -> QueueIntoIter<T> {
+        QueueIntoIter { queue: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Queue<T> {
+    type Item = &'a T;
+    type IntoIter = QueueIter<'a, T>;
+
+    fn into_iter(self) -> QueueIter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Queue<T> {
+    type Item = &'a mut T;
+    type IntoIter = QueueIterMut<'a, T>;
+
+    fn into_iter(self) -> QueueIterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Queue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// 二叉堆：最大堆，`pop` 总是返回最大的元素
+// 元素存放在 Vec 中，下标 i 的子节点是 2i+1 和 2i+2，每个节点都不小于它的子节点。
+// 和标准库一样，结构体本身不加约束，需要比较元素的方法才要求 `T: Ord`。
+//
+// 二叉堆没有 `iter_mut`：如果能随意修改元素，堆的性质就被破坏了，之后的 pop 会返回错误的结果。
+// 标准库的做法是只允许通过 `peek_mut` 修改堆顶，返回的守卫（guard）在离开作用域时把修改过的元素下沉到正确的位置。
+// 这里也实现了同样的 `PeekMut`。`iter` 和 `into_iter` 的顺序是内部数组的顺序，不保证有序
+pub struct BinaryHeap<T> {
+    data: Vec<T>,
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    pub fn new() -> BinaryHeap<T> {
+        BinaryHeap { data: Vec::new() }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.data.push(item);
+        self.sift_up(self.data.len() - 1);
+    }
+
+    // 把最后一个元素换到堆顶，再让它下沉
+    pub fn pop(&mut self) -> Option<T> {
+        let last = self.data.len().checked_sub(1)?;
+        self.data.swap(0, last);
+        let top = self.data.pop();
+        self.sift_down(0);
+        top
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T>> {
+        if self.data.is_empty() { None } else { Some(PeekMut { heap: self }) }
+    }
+
+    // 从小到大排序，和标准库的 `into_sorted_vec` 一样
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut sorted = Vec::with_capacity(self.len());
+        while let Some(item) = self.pop() {
+            sorted.push(item);
+        }
+        sorted.reverse();
+        sorted
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.data[index] <= self.data[parent] {
+                break;
+            }
+            self.data.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let left = 2 * index + 1;
+            let right = left + 1;
+            let mut largest = index;
+            if left < self.data.len() && self.data[left] > self.data[largest] {
+                largest = left;
+            }
+            if right < self.data.len() && self.data[right] > self.data[largest] {
+                largest = right;
+            }
+            if largest == index {
+                break;
+            }
+            self.data.swap(index, largest);
+            index = largest;
+        }
+    }
+}
+
+impl<T> BinaryHeap<T> {
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.data.iter()
+    }
+}
+
+impl<T: Ord> Default for BinaryHeap<T> {
+    fn default() -> BinaryHeap<T> {
+        BinaryHeap::new()
+    }
+}
+
+// 一次性建堆：从最后一个非叶子节点开始依次下沉，总共是 O(n)，比逐个 push 的 O(n log n) 快
+impl<T: Ord> FromIterator<T> for BinaryHeap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> BinaryHeap<T> {
+        let mut heap = BinaryHeap { data: iter.into_iter().collect() };
+        for index in (0..heap.data.len() / 2).rev() {
+            heap.sift_down(index);
+        }
+        heap
+    }
+}
+
+impl<T: Ord> Extend<T> for BinaryHeap<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T> IntoIterator for BinaryHeap<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> std::vec::IntoIter<T> {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BinaryHeap<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for BinaryHeap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// 堆顶的可变引用。通过 DerefMut 修改堆顶之后，Drop 负责恢复堆的性质
+pub struct PeekMut<'a, T: Ord> {
+    heap: &'a mut BinaryHeap<T>,
+}
+
+impl<T: Ord> Deref for PeekMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.heap.data[0]
+    }
+}
+
+impl<T: Ord> DerefMut for PeekMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.heap.data[0]
+    }
+}
+
+impl<T: Ord> Drop for PeekMut<'_, T> {
+    fn drop(&mut self) {
+        self.heap.sift_down(0);
+    }
+}
+
+// 单向链表：每个节点拥有下一个节点，`Option<Box<ListNode<T>>>` 就是“可能没有下一个”
+// 只能在头部 O(1) 地插入和删除；`Extend` 要先走到链表末尾
+pub struct LinkedList<T> {
+    head: Option<Box<ListNode<T>>>,
+    len: usize,
+}
+
+struct ListNode<T> {
+    value: T,
+    next: Option<Box<ListNode<T>>>,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> LinkedList<T> {
+        LinkedList { head: None, len: 0 }
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let next = self.head.take();
+        self.head = Some(Box::new(ListNode { value, next }));
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let node = self.head.take()?;
+        self.head = node.next;
+        self.len -= 1;
+        Some(node.value)
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    // 原地反转：逐个把节点从旧链表的头部摘下，接到新链表的头部，不分配也不复制元素
+    pub fn reverse(&mut self) {
+        let mut reversed = None;
+        let mut current = self.head.take();
+        while let Some(mut node) = current {
+            current = node.next.take();
+            node.next = reversed;
+            reversed = Some(node);
+        }
+        self.head = reversed;
+    }
+
+    pub fn iter(&self) -> ListIter<'_, T> {
+        ListIter { next: self.head.as_deref() }
+    }
+
+    pub fn iter_mut(&mut self) -> ListIterMut<'_, T> {
+        ListIterMut { next: self.head.as_deref_mut() }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> LinkedList<T> {
+        LinkedList::new()
+    }
+}
+
+// 默认的析构是递归的：丢弃头节点会丢弃它的 next，再丢弃 next 的 next……
+// 链表很长时会栈溢出，所以改成循环，一次只释放一个节点
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(mut node) = current {
+            current = node.next.take();
+        }
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> LinkedList<T> {
+        let mut list = LinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+// `tail` 始终指向最后一个 `next` 槽位，新节点直接放进去，保持迭代的顺序
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut tail = &mut self.head;
+        while let Some(node) = tail {
+            tail = &mut node.next;
+        }
+        for value in iter {
+            let node = tail.insert(Box::new(ListNode { value, next: None }));
+            tail = &mut node.next;
+            self.len += 1;
+        }
+    }
+}
+
+pub struct ListIter<'a, T> {
+    next: Option<&'a ListNode<T>>,
+}
+
+impl<'a, T> Iterator for ListIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.next?;
+        self.next = node.next.as_deref();
+        Some(&node.value)
+    }
+}
+
+// 可变引用不能复制，所以要用 take 把它从迭代器里拿出来，再把下一个节点放回去
+pub struct ListIterMut<'a, T> {
+    next: Option<&'a mut ListNode<T>>,
+}
+
+impl<'a, T> Iterator for ListIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        let node = self.next.take()?;
+        self.next = node.next.as_deref_mut();
+        Some(&mut node.value)
+    }
+}
+
+pub struct ListIntoIter<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Iterator for ListIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = ListIntoIter<T>;
+
+    fn into_iter(self) -> ListIntoIter<T> {
+        ListIntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = ListIter<'a, T>;
+
+    fn into_iter(self) -> ListIter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = ListIterMut<'a, T>;
+
+    fn into_iter(self) -> ListIterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// 泛型函数不关心具体是哪种容器，只要它能被迭代
+fn total<'a, C>(container: C) -> i32
+where
+    C: IntoIterator<Item = &'a i32>,
+{
+    container.into_iter().sum()
+}
+
+pub fn containers_example() {
+    // 栈：迭代顺序是 Vec 的倒序，最后压入的先出来
+    let mut stack: Stack<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+    stack.extend(["d".to_string()]);
+    for item in &mut stack {
+        item.push('!');
+    }
+    if let Some(top) = stack.peek_mut() {
+        top.push('?');
+    }
+    println!("stack: {:?}", stack);
+    println!("pop: {:?}, peek: {:?}", stack.pop(), stack.peek());
+
+    // 队列：环形缓冲区，出队空出来的槽位会被后面的入队重新使用
+    let mut queue: Queue<i32> = (1..=4).collect();
+    queue.pop_front();
+    queue.pop_front();
+    queue.extend([5, 6]);
+    println!("queue: {:?}, capacity still {}", queue, queue.capacity());
+
+    // 二叉堆：每次弹出最大的元素
+    let mut heap: BinaryHeap<&str> = ["pear", "apple", "fig"].into_iter().collect();
+    println!("heap: {:?}, peek: {:?}", heap, heap.peek());
+    // 通过 PeekMut 修改堆顶，守卫析构时会把它下沉
+    if let Some(mut top) = heap.peek_mut() {
+        *top = "banana";
+    }
+    println!("sorted: {:?}", heap.into_sorted_vec());
+
+    // 单向链表
+    let mut list: LinkedList<i32> = (1..=5).collect();
+    list.push_front(0);
+    list.reverse();
+    println!("list: {:?}, front: {:?}", list, list.front());
+
+    // 同一个泛型函数可以接受所有容器的借用
+    let numbers = [3, 1, 4, 1, 5];
+    let stack: Stack<i32> = numbers.into_iter().collect();
+    let queue: Queue<i32> = numbers.into_iter().collect();
+    let heap: BinaryHeap<i32> = numbers.into_iter().collect();
+    let list: LinkedList<i32> = numbers.into_iter().collect();
+    println!("totals: {:?}", [total(&stack), total(&queue), total(&heap), total(&list)]);
+
+    // 二叉堆不提供可变迭代：
+    // for item in &mut heap { *item = 0; } // 错误！`&mut BinaryHeap<i32>` 没有实现 IntoIterator
+}
+
+// 课程注册，见 lesson.rs
+pub struct ContainersLesson;
+
+impl crate::lesson::Lesson for ContainersLesson {
+    fn title(&self) -> &'static str {
+        "Generic Containers"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("containers.rs")
+    }
+
+    fn run(&self) {
+        containers_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::*;
+
+    // 可复现的伪随机数（线性同余），用来生成和标准库对照的操作序列
+    fn pseudo_random(seed: &mut u64) -> u64 {
+        *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        *seed >> 33
+    }
+
+    // 和 Vec 对照，迭代顺序是 Vec 的倒序
+    #[test]
+    fn stack_matches_vec() {
+        let mut stack: Stack<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let mut vec: Vec<String> = vec!["a".into(), "b".into(), "c".into()];
+        stack.extend(["d".to_string()]);
+        vec.extend(["d".to_string()]);
+        assert_eq!(stack.peek(), vec.last());
+        assert_eq!(stack.iter().collect::<Vec<_>>(), vec.iter().rev().collect::<Vec<_>>());
+        for item in &mut stack {
+            item.push('!');
+        }
+        if let Some(top) = stack.peek_mut() {
+            top.push('?');
+        }
+        assert_eq!(format!("{:?}", stack), r#"["d!?", "c!", "b!", "a!"]"#);
+        assert_eq!(stack.pop().as_deref(), Some("d!?"));
+        assert_eq!(stack.len(), 3);
+        assert_eq!((&stack).into_iter().count(), 3);
+        assert_eq!(stack.into_iter().collect::<Vec<_>>(), vec!["c!", "b!", "a!"]);
+    }
+
+    #[test]
+    fn empty_stack() {
+        let mut empty: Stack<i32> = Stack::default();
+        assert!(empty.is_empty() && empty.pop().is_none() && empty.peek().is_none());
+        empty.push(1);
+        empty.push(2);
+        assert_eq!(empty.pop(), Some(2));
+    }
+
+    // 和 VecDeque 对照一长串随机的入队、出队，中间会多次绕回缓冲区开头并扩容
+    #[test]
+    fn queue_matches_vec_deque() {
+        let mut queue: Queue<u64> = Queue::with_capacity(2);
+        let mut deque: VecDeque<u64> = VecDeque::new();
+        let mut seed = 42;
+        for _ in 0..1000 {
+            let roll = pseudo_random(&mut seed);
+            if roll.is_multiple_of(3) {
+                assert_eq!(queue.pop_front(), deque.pop_front());
+            } else {
+                queue.push_back(roll);
+                deque.push_back(roll);
+            }
+            assert_eq!(queue.len(), deque.len());
+            assert_eq!(queue.front(), deque.front());
+            assert!(queue.iter().eq(deque.iter()));
+        }
+        assert!(queue.capacity() >= queue.len());
+        for (ours, theirs) in queue.iter_mut().zip(deque.iter_mut()) {
+            *ours /= 2;
+            *theirs /= 2;
+        }
+        assert!(queue.into_iter().eq(deque));
+    }
+
+    // 绕回之后的 Debug 输出仍然是队列的顺序
+    #[test]
+    fn queue_wraps_around() {
+        let mut queue: Queue<i32> = (1..=4).collect();
+        assert_eq!(queue.capacity(), 4);
+        queue.pop_front();
+        queue.pop_front();
+        queue.extend([5, 6]);
+        assert_eq!(queue.capacity(), 4); // 5 和 6 放进了开头空出来的槽位
+        assert_eq!(format!("{:?}", queue), "[3, 4, 5, 6]");
+        for item in &mut queue {
+            *item *= 10;
+        }
+        assert_eq!((&queue).into_iter().copied().collect::<Vec<_>>(), vec![30, 40, 50, 60]);
+        let empty: Queue<i32> = Queue::default();
+        assert!(empty.is_empty() && empty.front().is_none());
+    }
+
+    // 和 std::collections::BinaryHeap 对照弹出的顺序
+    #[test]
+    fn heap_matches_std() {
+        let mut seed = 7;
+        let numbers: Vec<u64> = (0..200).map(|_| pseudo_random(&mut seed) % 50).collect();
+        let mut heap: BinaryHeap<u64> = numbers.iter().copied().collect();
+        let mut std_heap: std::collections::BinaryHeap<u64> = numbers.iter().copied().collect();
+        heap.extend([99, 0]);
+        std_heap.extend([99, 0]);
+        assert_eq!(heap.len(), std_heap.len());
+        assert_eq!(heap.peek(), std_heap.peek());
+        for _ in 0..50 {
+            assert_eq!(heap.pop(), std_heap.pop());
+        }
+        // iter 不保证顺序，排序后再比较
+        let mut ours: Vec<_> = heap.iter().collect();
+        let mut theirs: Vec<_> = std_heap.iter().collect();
+        ours.sort();
+        theirs.sort();
+        assert_eq!(ours, theirs);
+        // 通过 PeekMut 把堆顶改小，守卫析构时会把它下沉
+        if let (Some(mut top), Some(mut std_top)) = (heap.peek_mut(), std_heap.peek_mut()) {
+            *top = 1;
+            *std_top = 1;
+        }
+        assert_eq!(heap.peek(), std_heap.peek());
+        assert_eq!((&heap).into_iter().count(), heap.len());
+        assert_eq!(heap.into_sorted_vec(), std_heap.into_sorted_vec());
+    }
+
+    #[test]
+    fn heap_into_iter_and_empty() {
+        let words: BinaryHeap<&str> = ["pear", "apple", "fig"].into_iter().collect();
+        let mut drained: Vec<&str> = words.into_iter().collect();
+        drained.sort();
+        assert_eq!(drained, vec!["apple", "fig", "pear"]);
+        let mut empty: BinaryHeap<i32> = BinaryHeap::default();
+        assert!(empty.is_empty() && empty.pop().is_none() && empty.peek_mut().is_none());
+    }
+
+    // 和 std::collections::LinkedList（双向链表）对照
+    #[test]
+    fn list_matches_std() {
+        let mut list: LinkedList<i32> = (1..=5).collect();
+        let mut std_list: std::collections::LinkedList<i32> = (1..=5).collect();
+        list.push_front(0);
+        std_list.push_front(0);
+        list.extend([6, 7]);
+        std_list.extend([6, 7]);
+        assert!(list.iter().eq(std_list.iter()));
+        assert_eq!(list.len(), std_list.len());
+        for (ours, theirs) in list.iter_mut().zip(std_list.iter_mut()) {
+            *ours *= *ours;
+            *theirs *= *theirs;
+        }
+        assert_eq!(list.pop_front(), std_list.pop_front());
+        assert_eq!(list.front(), std_list.front());
+        assert_eq!(format!("{:?}", list), format!("{:?}", std_list));
+        list.reverse();
+        assert!(list.iter().eq(std_list.iter().rev()));
+        for item in &mut list {
+            *item += 1;
+        }
+        assert_eq!((&list).into_iter().next(), Some(&50));
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![50, 37, 26, 17, 10, 5, 2]);
+        let mut empty: LinkedList<String> = LinkedList::default();
+        assert!(empty.is_empty() && empty.pop_front().is_none() && empty.front().is_none());
+    }
+
+    // 十万个节点：如果 Drop 是递归的，这里会栈溢出
+    #[test]
+    fn long_list_drops_without_recursion() {
+        let long: LinkedList<u32> = (0..100_000).collect();
+        assert_eq!(long.len(), 100_000);
+        drop(long);
+    }
+
+    // 同一个泛型函数可以接受所有容器的借用
+    #[test]
+    fn generic_total_over_every_container() {
+        let numbers = [3, 1, 4, 1, 5];
+        let stack: Stack<i32> = numbers.into_iter().collect();
+        let queue: Queue<i32> = numbers.into_iter().collect();
+        let heap: BinaryHeap<i32> = numbers.into_iter().collect();
+        let list: LinkedList<i32> = numbers.into_iter().collect();
+        assert_eq!([total(&stack), total(&queue), total(&heap), total(&list)], [14; 4]);
+    }
+}
@@ -0,0 +1,338 @@
+use std::fmt;
+
+// 零拷贝的句子分析（Zero-Copy Excerpts）
+// `lifetimes.rs` 中的 `ImportantExcerpt<'a>` 只保存了一本小说的第一句话，`level()` 永远返回 3。
+// 这里把它扩展成一个文本分析工具：把文本切成句子、按关键词搜索、找最长和最短的句子、给句子排名。
+// 所有结果都是指向原文的切片（`&'a str`），整个过程没有复制任何输入文本。
+// 生命周期 `'a` 把每个结果和原文绑在一起：原文还活着，结果就有效；原文被释放，编译器就不允许再用结果。
+
+// 句子的结束标点：英文的 . ! ? 和中文的 。！？
+fn is_terminator(c: char) -> bool {
+    matches!(c, '.' | '!' | '?' | '。' | '！' | '？')
+}
+
+// 结束标点后面紧跟的右引号和右括号也属于这个句子
+fn is_closing(c: char) -> bool {
+    matches!(c, '"' | '\'' | ')' | '”' | '’' | '」' | '』' | '）')
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportantExcerpt<'a> {
+    part: &'a str,
+    // 在原文中的字节偏移，方便定位和高亮
+    start: usize,
+}
+
+impl<'a> ImportantExcerpt<'a> {
+    // 返回值的生命周期是 `'a` 而不是 `&self` 的生命周期：
+    // 即使这个 ImportantExcerpt 被丢弃了，拿到的切片仍然可以使用，只要原文还在
+    pub fn part(&self) -> &'a str {
+        self.part
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.start + self.part.len()
+    }
+
+    // 按字符数计算长度，中文和英文一视同仁
+    pub fn char_len(&self) -> usize {
+        self.part.chars().count()
+    }
+
+    // 句子中所有和关键词匹配的片段（ASCII 字母不区分大小写）
+    pub fn find(&self, keyword: &str) -> Vec<&'a str> {
+        let part = self.part;
+        match_starts(part, keyword)
+            .map(|i| &part[i..i + keyword.len()])
+            .collect()
+    }
+
+    pub fn contains(&self, keyword: &str) -> bool {
+        match_starts(self.part, keyword).next().is_some()
+    }
+
+    // 重要程度：句子里出现了多少次关键词
+    pub fn level(&self, keywords: &[&str]) -> usize {
+        keywords.iter().map(|keyword| match_starts(self.part, keyword).count()).sum()
+    }
+}
+
+impl fmt::Display for ImportantExcerpt<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.part)
+    }
+}
+
+// 所有匹配位置的起点。只在字符边界上比较，所以多字节字符不会被切开。
+// 英文关键词要匹配整个单词（`i` 不会匹配 `is` 里的 i），中文没有空格分词，按子串匹配
+fn match_starts<'t>(text: &'t str, keyword: &'t str) -> impl Iterator<Item = usize> + 't {
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphanumeric());
+    text.char_indices().map(|(i, _)| i).filter(move |&i| {
+        let end = i + keyword.len();
+        let matched = !keyword.is_empty()
+            && text.get(i..end).is_some_and(|window| window.eq_ignore_ascii_case(keyword));
+        matched
+            && !(is_word(keyword.chars().next()) && is_word(text[..i].chars().next_back()))
+            && !(is_word(keyword.chars().next_back()) && is_word(text[end..].chars().next()))
+    })
+}
+
+// 句子迭代器：每次从上次停下的位置继续往后找结束标点
+pub struct Sentences<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+pub fn sentences(text: &str) -> Sentences<'_> {
+    Sentences { text, position: 0 }
+}
+
+impl<'a> Iterator for Sentences<'a> {
+    type Item = ImportantExcerpt<'a>;
+
+    fn next(&mut self) -> Option<ImportantExcerpt<'a>> {
+        loop {
+            let rest = &self.text[self.position..];
+            if rest.is_empty() {
+                return None;
+            }
+
+            let mut chars = rest.char_indices().peekable();
+            let mut end = rest.len();
+            while let Some((i, c)) = chars.next() {
+                if !is_terminator(c) {
+                    continue;
+                }
+                // 小数点不是句号：3.14
+                if c == '.' && chars.peek().is_some_and(|(_, next)| next.is_ascii_digit()) {
+                    continue;
+                }
+                // 连续的标点（?!、……）和紧跟的右引号都算在这个句子里
+                end = i + c.len_utf8();
+                while let Some(&(j, next)) = chars.peek() {
+                    if !is_terminator(next) && !is_closing(next) {
+                        break;
+                    }
+                    end = j + next.len_utf8();
+                    chars.next();
+                }
+                break;
+            }
+
+            let start = self.position;
+            self.position += end;
+            let raw = &rest[..end];
+            let part = raw.trim();
+            // 只有空白的片段（比如两句之间的换行）直接跳过
+            if !part.is_empty() {
+                let leading = raw.len() - raw.trim_start().len();
+                return Some(ImportantExcerpt { part, start: start + leading });
+            }
+        }
+    }
+}
+
+// 包含关键词的句子。关键词只在搜索时用到，返回的句子只借用原文，
+// 所以 `keyword` 不需要 `'a`：收集起来的结果可以比关键词活得更久
+pub fn search<'a>(text: &'a str, keyword: &str) -> impl Iterator<Item = ImportantExcerpt<'a>> {
+    sentences(text).filter(move |sentence| sentence.contains(keyword))
+}
+
+// 最长的句子（按字符数），一样长时取靠前的
+pub fn longest_sentence(text: &str) -> Option<ImportantExcerpt<'_>> {
+    sentences(text).reduce(|best, sentence| if sentence.char_len() > best.char_len() { sentence } else { best })
+}
+
+pub fn shortest_sentence(text: &str) -> Option<ImportantExcerpt<'_>> {
+    sentences(text).reduce(|best, sentence| if sentence.char_len() < best.char_len() { sentence } else { best })
+}
+
+// 按重要程度排名：关键词出现次数多的在前，次数相同时更短的句子在前（信息更集中），再按原文顺序。
+// 没有命中任何关键词的句子不参与排名
+pub fn rank<'a>(text: &'a str, keywords: &[&str]) -> Vec<(ImportantExcerpt<'a>, usize)> {
+    let mut ranked: Vec<(ImportantExcerpt<'a>, usize)> = sentences(text)
+        .map(|sentence| (sentence, sentence.level(keywords)))
+        .filter(|&(_, level)| level > 0)
+        .collect();
+    ranked.sort_by(|(a, a_level), (b, b_level)| {
+        b_level
+            .cmp(a_level)
+            .then_with(|| a.char_len().cmp(&b.char_len()))
+            .then_with(|| a.start.cmp(&b.start))
+    });
+    ranked
+}
+
+const NOVEL: &str = "Call me Ishmael. Some years ago - never mind how long precisely - having little or no money \
+     in my purse, I thought I would sail about a little and see the watery part of the world. \
+     It is a way I have of driving off the spleen! Is it? \"Whenever I find myself growing grim \
+     about the mouth, I account it high time to get to sea.\" The voyage cost 2.50 dollars";
+
+const CHINESE: &str = "我是以实玛利。你好吗？好极了！！还有一句……“真的吗？”最后";
+
+pub fn excerpt_example() {
+    let novel = String::from(NOVEL);
+
+    // 句子迭代器：小数点不会切断句子，最后一句没有句号也会被返回
+    for sentence in sentences(&novel) {
+        println!("[{:>3}..{:>3}] {}", sentence.start(), sentence.end(), sentence);
+    }
+
+    // 中文标点
+    let parts: Vec<&str> = sentences(CHINESE).map(|s| s.part()).collect();
+    println!("{:?}", parts);
+
+    // 关键词搜索：返回的也是原文的切片，大小写不同也能匹配
+    let keyword = String::from("SEA");
+    let matches: Vec<ImportantExcerpt> = search(&novel, &keyword).collect();
+    drop(keyword); // 结果只借用原文，关键词可以先释放
+    println!("sentences mentioning \"sea\": {}", matches.len());
+    println!("find: {:?}", sentences(&novel).next().unwrap().find("ishmael"));
+
+    // 最长和最短的句子
+    let longest = longest_sentence(&novel).unwrap();
+    let shortest = shortest_sentence(&novel).unwrap();
+    println!("longest: {} chars, shortest: {:?}", longest.char_len(), shortest.part());
+
+    // 排名：两句都命中 3 次，更短的那句排在前面；"Is it?" 里的 "it" 不会被当成 "i"
+    for (excerpt, level) in rank(&novel, &["i", "sea", "world"]) {
+        println!("level {}: {}", level, excerpt);
+    }
+
+    // 生命周期保证了结果不会比原文活得更久
+    // let dangling;
+    // {
+    //     let text = String::from("Short-lived. Gone soon.");
+    //     dangling = sentences(&text).next().unwrap();
+    // } // 错误！`text` 在这里被释放，但 `dangling` 仍然借用着它
+    // println!("{}", dangling);
+}
+
+// 课程注册，见 lesson.rs
+pub struct ExcerptLesson;
+
+impl crate::lesson::Lesson for ExcerptLesson {
+    fn title(&self) -> &'static str {
+        "Zero-Copy Excerpts"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("excerpt.rs")
+    }
+
+    fn run(&self) {
+        excerpt_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 检查切片确实指向原文内部，而不是一份拷贝
+    fn borrows_from(text: &str, part: &str) -> bool {
+        let range = text.as_bytes().as_ptr_range();
+        let inner = part.as_bytes().as_ptr_range();
+        range.start <= inner.start && inner.end <= range.end
+    }
+
+    #[test]
+    fn splits_sentences() {
+        let all: Vec<ImportantExcerpt> = sentences(NOVEL).collect();
+        assert_eq!(all.len(), 6);
+        assert_eq!(all[0].part(), "Call me Ishmael.");
+        assert_eq!(all[2].part(), "It is a way I have of driving off the spleen!");
+        assert_eq!(all[3].part(), "Is it?");
+        assert!(all[4].part().ends_with("to sea.\""));
+        // 小数点不会切断句子，最后一句没有句号也会被返回
+        assert_eq!(all[5].part(), "The voyage cost 2.50 dollars");
+    }
+
+    // 零拷贝：每个结果都指向原文，偏移和切片一一对应
+    #[test]
+    fn sentences_borrow_from_the_text() {
+        let novel = String::from(NOVEL);
+        for sentence in sentences(&novel) {
+            assert!(borrows_from(&novel, sentence.part()));
+            assert_eq!(&novel[sentence.start()..sentence.end()], sentence.part());
+        }
+    }
+
+    #[test]
+    fn chinese_and_mixed_punctuation() {
+        let parts: Vec<&str> = sentences(CHINESE).map(|s| s.part()).collect();
+        assert_eq!(parts, vec!["我是以实玛利。", "你好吗？", "好极了！！", "还有一句……“真的吗？”", "最后"]);
+        assert_eq!(sentences("Rust 很快。It is safe! 对吧?").count(), 3);
+        assert_eq!(sentences("").count(), 0);
+        assert_eq!(sentences("  \n ").count(), 0);
+    }
+
+    // 关键词搜索：大小写不同也能匹配，英文按整个单词匹配
+    #[test]
+    fn search_and_find() {
+        let novel = String::from(NOVEL);
+        let all: Vec<ImportantExcerpt> = sentences(&novel).collect();
+        assert_eq!(search(&novel, "sea").map(|s| s.part()).collect::<Vec<_>>(), vec![all[4].part()]);
+        let found = all[0].find("ishmael");
+        assert_eq!(found, vec!["Ishmael"]);
+        assert!(borrows_from(&novel, found[0]));
+        assert_eq!(all[1].find("little").len(), 2);
+        assert!(all[1].find("").is_empty());
+        assert_eq!(sentences(CHINESE).filter(|s| s.contains("吗")).count(), 2);
+    }
+
+    // 搜索结果只借用原文：关键词是临时的 String，释放之后结果仍然可以使用
+    #[test]
+    fn search_results_outlive_the_keyword() {
+        let matches: Vec<ImportantExcerpt> = {
+            let keyword = String::from("WORLD");
+            search(NOVEL, &keyword).collect()
+        };
+        assert_eq!(matches.len(), 1);
+        assert!(matches[0].part().ends_with("part of the world."));
+    }
+
+    // `find` 的结果只依赖原文的生命周期，比产生它的 ImportantExcerpt 活得更久
+    #[test]
+    fn find_outlives_the_excerpt() {
+        let word = {
+            let first = sentences(NOVEL).next().unwrap();
+            first.find("me")[0]
+        };
+        assert_eq!(word, "me");
+    }
+
+    #[test]
+    fn longest_and_shortest() {
+        let all: Vec<ImportantExcerpt> = sentences(NOVEL).collect();
+        assert_eq!(longest_sentence(NOVEL), Some(all[1]));
+        assert_eq!(shortest_sentence(NOVEL).map(|s| s.part()), Some("Is it?"));
+        assert_eq!(longest_sentence(CHINESE).unwrap().part(), "还有一句……“真的吗？”");
+        assert_eq!(longest_sentence(""), None);
+    }
+
+    #[test]
+    fn ranking() {
+        let all: Vec<ImportantExcerpt> = sentences(NOVEL).collect();
+        let keywords = ["i", "sea", "world"];
+        let ranked = rank(NOVEL, &keywords);
+        // 两句都命中 3 次，更短的那句排在前面；"Is it?" 里的 "it" 不会被当成 "i"
+        assert_eq!((ranked[0].0, ranked[0].1), (all[4], 3));
+        assert_eq!((ranked[1].0, ranked[1].1), (all[1], 3));
+        assert_eq!(ranked.len(), 3);
+        assert!(ranked.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert_eq!(
+            ranked.iter().map(|(_, level)| level).sum::<usize>(),
+            all.iter().map(|s| s.level(&keywords)).sum::<usize>()
+        );
+        assert!(rank(NOVEL, &["whale"]).is_empty());
+    }
+}
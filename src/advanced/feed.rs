@@ -0,0 +1,457 @@
+use std::cmp::Ordering;
+use std::num::NonZeroUsize;
+
+use super::traits::{NewsArticle, Summary, Tweet};
+
+// 信息流（Content Feed）
+// `traits.rs` 里的 `Summary` 只被 `notify` 调用过一次。真实的程序会把各种内容放进同一个集合：
+// 新闻、推文、博客文章、回复串……它们的类型各不相同，但都实现了 `Summary`。
+// `Vec<Box<dyn Summary>>` 可以同时存放这些不同类型的值，排序、筛选和渲染都只通过特征的方法完成，
+// 信息流本身不需要知道里面到底是什么类型。
+
+pub struct BlogPost {
+    pub title: String,
+    pub author: String,
+    pub body: String,
+    pub published_at: u64,
+    pub tags: Vec<String>,
+}
+
+impl Summary for BlogPost {
+    fn summarize_author(&self) -> String {
+        self.author.clone()
+    }
+
+    // 正文只取开头的 40 个字符
+    fn summarize(&self) -> String {
+        let mut excerpt: String = self.body.chars().take(40).collect();
+        if excerpt.len() < self.body.len() {
+            excerpt.push('…');
+        }
+        format!("{} by {}: {}", self.title, self.author, excerpt)
+    }
+
+    fn published_at(&self) -> Option<u64> {
+        Some(self.published_at)
+    }
+
+    fn kind(&self) -> &'static str {
+        "blog"
+    }
+
+    fn tags(&self) -> Vec<String> {
+        self.tags.clone()
+    }
+}
+
+// 一条推文和它下面的回复
+pub struct ReplyThread {
+    pub root: Tweet,
+    pub replies: Vec<Tweet>,
+    pub published_at: u64,
+}
+
+impl Summary for ReplyThread {
+    fn summarize_author(&self) -> String {
+        self.root.summarize_author()
+    }
+
+    fn summarize(&self) -> String {
+        format!("{} [+{} replies]", self.root.summarize(), self.replies.len())
+    }
+
+    fn published_at(&self) -> Option<u64> {
+        Some(self.published_at)
+    }
+
+    fn kind(&self) -> &'static str {
+        "thread"
+    }
+
+    // 整串回复里出现过的所有话题，按第一次出现的顺序
+    fn tags(&self) -> Vec<String> {
+        let mut tags = self.root.tags();
+        for tag in self.replies.iter().flat_map(|reply| reply.tags()) {
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        tags
+    }
+}
+
+// `NewsArticle` 和 `Tweet` 没有时间字段。不需要修改它们，包一层就能补上发布时间，
+// 其他方法全部转发给里面的值
+pub struct Dated<T> {
+    pub item: T,
+    pub at: u64,
+}
+
+impl<T: Summary> Summary for Dated<T> {
+    fn summarize_author(&self) -> String {
+        self.item.summarize_author()
+    }
+
+    fn summarize(&self) -> String {
+        self.item.summarize()
+    }
+
+    fn published_at(&self) -> Option<u64> {
+        Some(self.at)
+    }
+
+    fn kind(&self) -> &'static str {
+        self.item.kind()
+    }
+
+    fn tags(&self) -> Vec<String> {
+        self.item.tags()
+    }
+}
+
+#[derive(Default)]
+pub struct Feed {
+    items: Vec<Box<dyn Summary>>,
+}
+
+// 按时间比较，没有时间的内容不管哪种顺序都排在最后
+fn by_time(a: &dyn Summary, b: &dyn Summary, newest_first: bool) -> Ordering {
+    match (a.published_at(), b.published_at()) {
+        (Some(a), Some(b)) if newest_first => b.cmp(&a),
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+impl Feed {
+    pub fn new() -> Feed {
+        Feed { items: Vec::new() }
+    }
+
+    // 任何实现了 Summary 的类型都可以放进来，装箱之后它们的类型就统一成了 `Box<dyn Summary>`
+    pub fn push(&mut self, item: impl Summary + 'static) {
+        self.items.push(Box::new(item));
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    // 稳定排序：时间相同的内容保持添加时的顺序
+    pub fn sort_newest_first(&mut self) {
+        self.items.sort_by(|a, b| by_time(a.as_ref(), b.as_ref(), true));
+    }
+
+    pub fn sort_oldest_first(&mut self) {
+        self.items.sort_by(|a, b| by_time(a.as_ref(), b.as_ref(), false));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Summary> {
+        self.items.iter().map(|item| item.as_ref() as &dyn Summary)
+    }
+
+    pub fn filter<'a>(&'a self, predicate: impl Fn(&dyn Summary) -> bool + 'a) -> impl Iterator<Item = &'a dyn Summary> {
+        self.iter().filter(move |item| predicate(*item))
+    }
+}
+
+// 常用的筛选条件，都只依赖特征提供的元数据
+pub fn tagged(tag: &str) -> impl Fn(&dyn Summary) -> bool + '_ {
+    move |item| item.tags().iter().any(|t| t.eq_ignore_ascii_case(tag))
+}
+
+pub fn of_kind(kind: &str) -> impl Fn(&dyn Summary) -> bool + '_ {
+    move |item| item.kind() == kind
+}
+
+pub fn by_author(author: &str) -> impl Fn(&dyn Summary) -> bool + '_ {
+    move |item| item.summarize_author() == author
+}
+
+// 发布时间在 [from, to) 之间，没有时间的内容不算
+pub fn published_between(from: u64, to: u64) -> impl Fn(&dyn Summary) -> bool {
+    move |item| item.published_at().is_some_and(|at| from <= at && at < to)
+}
+
+pub struct Page<'a> {
+    // 从 1 开始
+    pub number: usize,
+    pub total_pages: usize,
+    pub total_items: usize,
+    pub items: Vec<&'a dyn Summary>,
+}
+
+// 把内容按每页 page_size 条分页。没有内容时也返回一个空的第 1 页，方便渲染“暂无内容”
+// 每页 0 条没有意义，`NonZeroUsize` 让这种调用根本无法写出来，函数里也就不需要检查
+pub fn paginate<'a>(items: impl IntoIterator<Item = &'a dyn Summary>, page_size: NonZeroUsize) -> Vec<Page<'a>> {
+    let page_size = page_size.get();
+    let items: Vec<&dyn Summary> = items.into_iter().collect();
+    let total_items = items.len();
+    let total_pages = total_items.div_ceil(page_size).max(1);
+    let mut pages: Vec<Page> = items
+        .chunks(page_size)
+        .enumerate()
+        .map(|(i, chunk)| Page { number: i + 1, total_pages, total_items, items: chunk.to_vec() })
+        .collect();
+    if pages.is_empty() {
+        pages.push(Page { number: 1, total_pages, total_items, items: Vec::new() });
+    }
+    pages
+}
+
+// Unix 时间戳转换成 UTC 的日期和时间（Howard Hinnant 的 civil_from_days 算法）
+pub fn format_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02} {:02}:{:02}", year, month, day, rem / 3_600, rem % 3_600 / 60)
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+impl Page<'_> {
+    pub fn render_plain(&self) -> String {
+        let mut out = format!("Page {}/{} ({} items)\n", self.number, self.total_pages, self.total_items);
+        if self.items.is_empty() {
+            out.push_str("(nothing here yet)\n");
+        }
+        for item in &self.items {
+            let time = item.published_at().map_or_else(|| String::from("-"), format_timestamp);
+            out.push_str(&format!("{:<16}  {:<6}  {}", time, item.kind(), item.summarize()));
+            for tag in item.tags() {
+                out.push_str(&format!(" #{}", tag));
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    pub fn render_markdown(&self) -> String {
+        let mut out = format!("### Page {} of {}\n\n", self.number, self.total_pages);
+        if self.items.is_empty() {
+            out.push_str("_Nothing here yet._\n");
+        }
+        for item in &self.items {
+            out.push_str(&format!("- **{}**", item.kind()));
+            if let Some(at) = item.published_at() {
+                out.push_str(&format!(" · {}", format_timestamp(at)));
+            }
+            out.push_str(&format!(" — {}", escape_markdown(&item.summarize())));
+            let tags = item.tags();
+            if !tags.is_empty() {
+                let tags: Vec<String> = tags.iter().map(|tag| format!("`#{}`", tag)).collect();
+                out.push_str(&format!(" · {}", tags.join(" ")));
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+fn tweet(username: &str, content: &str) -> Tweet {
+    Tweet { username: username.to_string(), content: content.to_string(), reply: false, retweet: false }
+}
+
+// 五种不同的类型放进同一个 Vec<Box<dyn Summary>>
+fn sample_feed() -> Feed {
+    let mut feed = Feed::new();
+    feed.push(BlogPost {
+        title: String::from("Trait objects in practice"),
+        author: String::from("ferris"),
+        body: String::from("Box<dyn Trait> stores values of different types behind one pointer type."),
+        published_at: 1_700_000_000,
+        tags: vec![String::from("rust"), String::from("traits")],
+    });
+    feed.push(Dated { item: tweet("rustlang", "Rust 1.75 is out! #rust #release"), at: 1_703_721_600 });
+    feed.push(NewsArticle {
+        headline: String::from("Penguins win Stanley Cup in overtime!"),
+        location: String::from("Pittsburgh, PA"),
+        author: String::from("Iceburgh"),
+        content: String::from("The Pittsburgh Penguins once again are the best hockey team in the NHL."),
+    });
+    feed.push(ReplyThread {
+        root: tweet("alice", "How do I store different types in one Vec? #rust"),
+        replies: vec![
+            tweet("bob", "Use Box<dyn Trait>. #traits"),
+            tweet("carol", "Or an enum if the set of types is closed. #rust #enums"),
+        ],
+        published_at: 1_700_003_600,
+    });
+    feed.push(Dated {
+        item: NewsArticle {
+            headline: String::from("New compiler release"),
+            location: String::from("Internet"),
+            author: String::from("The Rust Team"),
+            content: String::from("Faster builds and better diagnostics."),
+        },
+        at: 1_703_721_600,
+    });
+    feed.push(tweet("horse_ebooks", "of course, as you probably already know, people"));
+    feed
+}
+
+pub fn feed_example() {
+    let mut feed = sample_feed();
+    println!("{} items", feed.len());
+
+    // 排序：最新的在前，没有时间的在最后，时间相同的保持添加顺序
+    feed.sort_newest_first();
+    let kinds: Vec<&str> = feed.iter().map(|item| item.kind()).collect();
+    println!("newest first: {:?}", kinds);
+
+    // 用特征提供的元数据筛选
+    for item in feed.filter(tagged("Rust")) {
+        println!("#rust: {}", item.summarize());
+    }
+    // 条件也可以组合，或者直接写闭包
+    let recent_news = feed.filter(|item| of_kind("news")(item) && item.published_at().is_some());
+    println!("dated news: {}", recent_news.count());
+
+    // 分页：页大小是 `NonZeroUsize`，0 在构造时就被拒绝了
+    println!("NonZeroUsize::new(0) = {:?}", NonZeroUsize::new(0));
+    let pages = paginate(feed.iter(), NonZeroUsize::new(4).unwrap());
+
+    // 渲染
+    print!("{}", pages[0].render_plain());
+    print!("{}", pages[1].render_markdown());
+}
+
+// 课程注册，见 lesson.rs
+pub struct FeedLesson;
+
+impl crate::lesson::Lesson for FeedLesson {
+    fn title(&self) -> &'static str {
+        "Content Feed"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("feed.rs")
+    }
+
+    fn run(&self) {
+        feed_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page_size(n: usize) -> NonZeroUsize {
+        NonZeroUsize::new(n).unwrap()
+    }
+
+    #[test]
+    fn push_and_len() {
+        assert!(Feed::new().is_empty());
+        assert_eq!(sample_feed().len(), 6);
+    }
+
+    // 最新的在前，没有时间的在最后，时间相同的保持添加顺序
+    #[test]
+    fn sorting_is_stable_and_puts_undated_last() {
+        let mut feed = sample_feed();
+        feed.sort_newest_first();
+        let kinds: Vec<&str> = feed.iter().map(|item| item.kind()).collect();
+        assert_eq!(kinds, vec!["tweet", "news", "thread", "blog", "news", "tweet"]);
+        assert_eq!(feed.iter().last().map(|item| item.published_at()), Some(None));
+        feed.sort_oldest_first();
+        let times: Vec<Option<u64>> = feed.iter().map(|item| item.published_at()).collect();
+        assert_eq!(
+            times,
+            vec![Some(1_700_000_000), Some(1_700_003_600), Some(1_703_721_600), Some(1_703_721_600), None, None]
+        );
+    }
+
+    #[test]
+    fn filters() {
+        let mut feed = sample_feed();
+        feed.sort_newest_first();
+        let rust: Vec<String> = feed.filter(tagged("Rust")).map(|item| item.summarize()).collect();
+        assert_eq!(rust.len(), 3);
+        assert!(rust[1].starts_with("alice: How do I store"));
+        assert_eq!(feed.filter(tagged("enums")).count(), 1);
+        assert_eq!(feed.filter(of_kind("news")).count(), 2);
+        assert_eq!(feed.filter(published_between(1_700_000_000, 1_700_003_600)).count(), 1);
+        assert_eq!(feed.filter(by_author("@alice")).count(), 1);
+        assert_eq!(feed.filter(by_author("Iceburgh")).count(), 1);
+        let recent_news = feed.filter(|item| of_kind("news")(item) && item.published_at().is_some());
+        assert_eq!(recent_news.count(), 1);
+        let thread_tags = feed.filter(of_kind("thread")).next().unwrap().tags();
+        assert_eq!(thread_tags, vec!["rust", "traits", "enums"]);
+    }
+
+    #[test]
+    fn pagination() {
+        let mut feed = sample_feed();
+        feed.sort_newest_first();
+        let pages = paginate(feed.iter(), page_size(4));
+        assert_eq!(pages.len(), 2);
+        assert_eq!((pages[0].items.len(), pages[1].items.len()), (4, 2));
+        assert!(pages.iter().all(|page| page.total_items == 6 && page.total_pages == 2));
+        let rust_pages = paginate(feed.filter(tagged("rust")), page_size(2));
+        assert_eq!(rust_pages.iter().map(|page| page.items.len()).collect::<Vec<_>>(), vec![2, 1]);
+        let singles = paginate(feed.iter(), NonZeroUsize::MIN);
+        assert_eq!((singles.len(), singles[5].number, singles[5].total_pages), (6, 6, 6));
+    }
+
+    // 没有内容时返回一个空的第 1 页
+    #[test]
+    fn empty_result_is_one_empty_page() {
+        let feed = sample_feed();
+        let empty = paginate(feed.filter(tagged("python")), page_size(10));
+        assert_eq!((empty.len(), empty[0].number, empty[0].items.len()), (1, 1, 0));
+        assert_eq!(empty[0].render_plain(), "Page 1/1 (0 items)\n(nothing here yet)\n");
+    }
+
+    #[test]
+    fn rendering() {
+        let mut feed = sample_feed();
+        feed.sort_newest_first();
+        let pages = paginate(feed.iter(), page_size(4));
+        let plain = pages[0].render_plain();
+        assert!(plain.starts_with("Page 1/2 (6 items)\n2023-12-28 00:00  tweet   rustlang: Rust 1.75 is out!"));
+        assert!(plain.contains("2023-11-14 22:13  blog    Trait objects in practice by ferris: Box<dyn Trait> stores values of differen… #rust #traits\n"));
+        assert!(plain.contains("alice: How do I store different types in one Vec? #rust [+2 replies] #rust #traits #enums"));
+        assert_eq!(
+            pages[1].render_markdown(),
+            "### Page 2 of 2\n\n\
+             - **news** — Penguins win Stanley Cup in overtime!, by Iceburgh (Pittsburgh, PA)\n\
+             - **tweet** — horse\\_ebooks: of course, as you probably already know, people\n"
+        );
+    }
+
+    #[test]
+    fn timestamps_and_escaping() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00");
+        assert_eq!(escape_markdown("a_b*c"), "a\\_b\\*c");
+    }
+}
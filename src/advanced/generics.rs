@@ -4,6 +4,7 @@ pub fn generics_example() {
 
     // 函数中的泛型
     // 我们可以编写一个函数，它适用于任何类型的数据，而不是只适用于特定类型。
+    // 注意：这个版本在空切片上会 panic，并且要求 Copy；selection.rs 中有返回 Option<&T> 的版本。
     fn largest<T: PartialOrd + Copy>(list: &[T]) -> T {
         let mut largest = list[0];
 
@@ -49,11 +50,15 @@ pub fn generics_example() {
 
     // 枚举定义中的泛型
     // 枚举也可以使用泛型类型参数。
+    // 这里只展示定义的写法，它们不会被构造，所以用 `#[allow(dead_code)]` 关掉“从未使用”的警告。
+    // 这两个枚举在 option_result.rs 中有完整的实现，并和标准库逐一对比
+    #[allow(dead_code)]
     enum Option<T> {
         Some(T),
         None,
     }
 
+    #[allow(dead_code)]
     enum Result<T, E> {
         Ok(T),
         Err(E),
@@ -61,6 +66,7 @@ pub fn generics_example() {
 
     // 方法定义中的泛型
     // 可以在结构体或枚举的方法中使用泛型。
+    // containers.rs 用同样的 `impl<T>` 写法实现了栈、队列、二叉堆和链表。
     impl<T> Point<T> {
         fn x(&self) -> &T {
             &self.x
@@ -81,4 +87,26 @@ pub fn generics_example() {
     // 泛型参数的性能
     // Rust 通过在编译时执行泛型代码的单态化（monomorphization）来保证运行时性能。
     // 单态化是编译器将泛型代码替换为特定具体类型的代码的过程。
-}
\ No newline at end of file
+    // 泛型参数也可以是常量值，例如 matrix.rs 中 `Matrix<T, const R: usize, const C: usize>` 的行数和列数。
+}
+
+// 课程注册，见 lesson.rs
+pub struct GenericsLesson;
+
+impl crate::lesson::Lesson for GenericsLesson {
+    fn title(&self) -> &'static str {
+        "Generics"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("generics.rs")
+    }
+
+    fn run(&self) {
+        generics_example()
+    }
+}
@@ -8,6 +8,7 @@ pub fn lifetimes_example() {
     // 而不影响生命周期本身。
 
     // 这是一个泛型生命周期参数 `'a` 的例子
+    // 这里比较的是字节数，longest.rs 提供了按字符和字素簇比较的版本
     fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
         if x.len() > y.len() {
             x
@@ -25,6 +26,7 @@ pub fn lifetimes_example() {
 
     // 结构体定义中的生命周期注解
     // 结构体可以持有引用，但这种情况下需要为结构体定义添加生命周期注解。
+    // excerpt.rs 把它扩展成了零拷贝的句子分析工具
     #[derive(Debug)]
     struct ImportantExcerpt<'a> {
         part: &'a str,
@@ -84,4 +86,25 @@ pub fn lifetimes_example() {
     let announcement = "Today's news";
     let result = longest_with_an_announcement(string1.as_str(), "short", announcement);
     println!("The longest string with announcement is: {}", result);
-}
\ No newline at end of file
+}
+
+// 课程注册，见 lesson.rs
+pub struct LifetimesLesson;
+
+impl crate::lesson::Lesson for LifetimesLesson {
+    fn title(&self) -> &'static str {
+        "Lifetimes"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("lifetimes.rs")
+    }
+
+    fn run(&self) {
+        lifetimes_example()
+    }
+}
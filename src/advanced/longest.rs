@@ -0,0 +1,339 @@
+use std::marker::PhantomData;
+
+// 按“看到的长度”比较字符串（Unicode-Aware Longest）
+// `lifetimes.rs` 中的 `longest` 比较的是 `x.len()`，也就是字节数。
+// UTF-8 中一个汉字占 3 个字节，所以 "生命周期"（4 个字）有 12 个字节，会“长过” 8 个字母的 "lifetime"。
+// 这里把“长度”抽象成一个特征，同一组函数可以按字节、按 `char`、按字素簇（用户看到的一个字符）比较。
+// 不管用哪种度量，返回值仍然是借用自输入的 `&'a str`，生命周期的规则和原来的 `longest` 完全一样。
+
+pub trait LengthMetric {
+    fn length(s: &str) -> usize;
+}
+
+// 字节数：`str::len`，O(1)，适合判断存储和传输的大小
+pub struct Bytes;
+
+// Unicode 标量值的个数：`chars().count()`，中文和英文一个字都算 1
+pub struct Chars;
+
+// 字素簇的个数：带组合音标的 "é"（e + U+0301）、国旗 🇨🇳、家庭表情 👨‍👩‍👧 都算 1
+pub struct Graphemes;
+
+impl LengthMetric for Bytes {
+    fn length(s: &str) -> usize {
+        s.len()
+    }
+}
+
+impl LengthMetric for Chars {
+    fn length(s: &str) -> usize {
+        s.chars().count()
+    }
+}
+
+impl LengthMetric for Graphemes {
+    fn length(s: &str) -> usize {
+        graphemes(s).count()
+    }
+}
+
+// 两个中更长的一个。一样长时返回第一个
+pub fn longest<'a, M: LengthMetric>(x: &'a str, y: &'a str) -> &'a str {
+    if M::length(y) > M::length(x) { y } else { x }
+}
+
+// 一组字符串中最长的一个，空迭代器返回 None。一样长时返回最先出现的
+pub fn longest_in<'a, M, I>(strings: I) -> Option<&'a str>
+where
+    M: LengthMetric,
+    I: IntoIterator<Item = &'a str>,
+{
+    strings
+        .into_iter()
+        .map(|s| (M::length(s), s))
+        .reduce(|best, item| if item.0 > best.0 { item } else { best })
+        .map(|(_, s)| s)
+}
+
+// 按闭包取出的字符串比较，返回的是元素本身而不是那个字符串。
+// `key` 返回的切片借用自元素，所以它的生命周期和元素相同
+pub fn longest_by_key<'a, M, T, I, F>(items: I, key: F) -> Option<&'a T>
+where
+    M: LengthMetric,
+    T: ?Sized + 'a,
+    I: IntoIterator<Item = &'a T>,
+    F: Fn(&'a T) -> &'a str,
+{
+    items
+        .into_iter()
+        .map(|item| (M::length(key(item)), item))
+        .reduce(|best, entry| if entry.0 > best.0 { entry } else { best })
+        .map(|(_, item)| item)
+}
+
+// 字素簇切分
+// 完整的规则（UAX #29）需要 Unicode 字符数据库，这里只实现常见的几条，不依赖外部 crate：
+//   - CR LF 是一个簇
+//   - 组合音标、变体选择符、肤色修饰符、标签字符和零宽连接符（ZWJ）附着在前一个字符上
+//   - ZWJ 后面的字符也并入当前簇（👨‍👩‍👧 这样的表情序列）
+//   - 区域指示符两两配对成一面国旗
+// 韩文音节的拆分形式、印度系文字的辅音连缀等没有处理，会被切成多个簇
+
+fn is_extend(c: char) -> bool {
+    matches!(c,
+        '\u{0300}'..='\u{036F}'       // 组合变音符号
+        | '\u{0483}'..='\u{0489}'     // 西里尔组合符号
+        | '\u{0591}'..='\u{05BD}'     // 希伯来点符
+        | '\u{064B}'..='\u{065F}'     // 阿拉伯元音符号
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{1DC0}'..='\u{1DFF}'
+        | '\u{20D0}'..='\u{20FF}'     // 符号用组合标记，比如 1⃣ 中的 U+20E3
+        | '\u{3099}'..='\u{309A}'     // 日文浊音、半浊音组合符号
+        | '\u{FE00}'..='\u{FE0F}'     // 变体选择符
+        | '\u{FE20}'..='\u{FE2F}'
+        | '\u{1F3FB}'..='\u{1F3FF}'   // 肤色修饰符
+        | '\u{E0020}'..='\u{E007F}'   // 标签字符（英格兰等地区旗帜）
+        | '\u{E0100}'..='\u{E01EF}'   // 补充变体选择符
+    )
+}
+
+const ZWJ: char = '\u{200D}';
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+pub struct GraphemeIndices<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+pub fn graphemes(text: &str) -> impl Iterator<Item = &str> {
+    GraphemeIndices { text, position: 0 }.map(|(_, cluster)| cluster)
+}
+
+impl<'a> Iterator for GraphemeIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        let rest = &self.text[self.position..];
+        let mut chars = rest.char_indices().peekable();
+        let (_, first) = chars.next()?;
+        let mut end = first.len_utf8();
+        let mut after_zwj = first == ZWJ;
+        // 第一个区域指示符可以再吸收一个，组成国旗
+        let mut pending_flag = is_regional_indicator(first);
+
+        if first == '\r' && chars.peek().is_some_and(|&(_, c)| c == '\n') {
+            end += 1;
+        } else if first != '\r' && first != '\n' {
+            while let Some(&(i, c)) = chars.peek() {
+                let joins = is_extend(c)
+                    || c == ZWJ
+                    || after_zwj
+                    || (pending_flag && is_regional_indicator(c));
+                if !joins {
+                    break;
+                }
+                pending_flag = false;
+                after_zwj = c == ZWJ;
+                end = i + c.len_utf8();
+                chars.next();
+            }
+        }
+
+        let start = self.position;
+        self.position += end;
+        Some((start, &rest[..end]))
+    }
+}
+
+// 同一组函数的另一种写法：把度量放在类型参数里，`Longest::<Chars>::of(a, b)`
+pub struct Longest<M>(PhantomData<M>);
+
+impl<M: LengthMetric> Longest<M> {
+    pub fn of<'a>(x: &'a str, y: &'a str) -> &'a str {
+        longest::<M>(x, y)
+    }
+}
+
+struct Chapter {
+    number: u32,
+    title: String,
+}
+
+pub fn longest_example() {
+    // 字节数会让中文“变长”
+    let chinese = "生命周期";
+    let english = "lifetime";
+    println!(
+        "{:?} vs {:?}: bytes -> {}, chars -> {}, graphemes -> {}",
+        chinese,
+        english,
+        longest::<Bytes>(chinese, english),
+        longest::<Chars>(chinese, english),
+        longest::<Graphemes>(chinese, english)
+    );
+
+    // 组合音标：e + U+0301 看起来是一个 é，但有两个 char
+    let decomposed = "cafe\u{301}";
+    println!(
+        "{:?}: {} bytes, {} chars, {} graphemes",
+        decomposed,
+        Bytes::length(decomposed),
+        Chars::length(decomposed),
+        Graphemes::length(decomposed)
+    );
+
+    // 表情、国旗：一个字素簇可能由好几个 char 组成
+    let family = "👨\u{200D}👩\u{200D}👧";
+    println!("{}: {} chars, {} grapheme", family, Chars::length(family), Graphemes::length(family));
+    println!("{:?}", graphemes("🇨🇳🇯🇵🇺").collect::<Vec<_>>());
+
+    // 一组字符串中最长的
+    // 国旗每个区域指示符占 4 个字节；分解形式的 ï 多一个 char，但仍是一个字素簇
+    let words = ["Rust", "生命周期", "借用", "naïve", "nai\u{308}ve", "🇨🇳🇯🇵"];
+    println!(
+        "bytes: {:?}, chars: {:?}, graphemes: {:?}",
+        longest_in::<Bytes, _>(words),
+        longest_in::<Chars, _>(words),
+        longest_in::<Graphemes, _>(words)
+    );
+
+    // 按闭包比较：返回整个元素
+    let chapters = vec![
+        Chapter { number: 1, title: String::from("变量与可变性") },
+        Chapter { number: 2, title: String::from("Ownership") },
+        Chapter { number: 3, title: String::from("Résumé of Lifetimes") },
+    ];
+    let by_chars = longest_by_key::<Chars, _, _, _>(&chapters, |c| c.title.as_str()).unwrap();
+    println!("longest title: {} {}", by_chars.number, by_chars.title);
+
+    // 生命周期规则不变：结果不能比任何一个输入活得更久
+    let outer = String::from("long string is long");
+    {
+        let inner = String::from("很长很长的字符串");
+        let result = longest::<Chars>(outer.as_str(), inner.as_str());
+        println!("The longest string is {}", result);
+    }
+    // let result;
+    // {
+    //     let inner = String::from("很长很长的字符串");
+    //     result = longest::<Chars>(outer.as_str(), inner.as_str());
+    // } // 错误！`inner` 在这里被释放，但 `result` 可能借用着它
+    // println!("The longest string is {}", result);
+}
+
+// 课程注册，见 lesson.rs
+pub struct LongestLesson;
+
+impl crate::lesson::Lesson for LongestLesson {
+    fn title(&self) -> &'static str {
+        "Unicode-Aware Longest"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("longest.rs")
+    }
+
+    fn run(&self) {
+        longest_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 字节数会让中文“变长”
+    #[test]
+    fn metrics_disagree_on_chinese() {
+        let chinese = "生命周期";
+        let english = "lifetime";
+        assert_eq!((chinese.len(), english.len()), (12, 8));
+        assert_eq!(longest::<Bytes>(chinese, english), chinese);
+        assert_eq!(longest::<Chars>(chinese, english), english);
+        assert_eq!(longest::<Graphemes>(chinese, english), english);
+    }
+
+    // 组合音标：预组合的 é 和 e + U+0301 看起来一样
+    #[test]
+    fn combining_marks() {
+        let composed = "caf\u{e9}";
+        let decomposed = "cafe\u{301}";
+        assert_eq!(Chars::length(composed), 4);
+        assert_eq!(Chars::length(decomposed), 5);
+        assert_eq!(Graphemes::length(composed), 4);
+        assert_eq!(Graphemes::length(decomposed), 4);
+        assert_eq!(longest::<Chars>(composed, decomposed), decomposed);
+        // 字素簇一样长，返回第一个
+        assert_eq!(longest::<Graphemes>(composed, decomposed), composed);
+        assert_eq!(longest::<Graphemes>(decomposed, composed), decomposed);
+    }
+
+    // 越南语和日文的组合符号
+    #[test]
+    fn vietnamese_and_japanese_marks() {
+        let vietnamese = "Tie\u{302}\u{301}ng Vie\u{302}\u{323}t"; // Tiếng Việt，分解形式
+        assert_eq!(Graphemes::length(vietnamese), 10);
+        assert_eq!(Chars::length(vietnamese), 14);
+        assert_eq!(Graphemes::length("か\u{3099}"), 1); // が
+    }
+
+    // 表情、国旗和换行
+    #[test]
+    fn emoji_flags_and_newlines() {
+        let clusters: Vec<&str> = graphemes("🇨🇳🇯🇵🇺").collect();
+        assert_eq!(clusters, vec!["🇨🇳", "🇯🇵", "🇺"]);
+        let family = "👨\u{200D}👩\u{200D}👧";
+        assert_eq!(Graphemes::length(family), 1);
+        assert_eq!(Chars::length(family), 5);
+        assert_eq!(Graphemes::length("👍🏽ok"), 3);
+        assert_eq!(Graphemes::length("a\r\nb"), 3);
+        assert_eq!(Graphemes::length(""), 0);
+        let sample = "Rust 🦀 很安全\u{301}!";
+        assert_eq!(graphemes(sample).collect::<String>(), sample);
+    }
+
+    #[test]
+    fn longest_in_iterator() {
+        let words = ["Rust", "生命周期", "借用", "naïve", "nai\u{308}ve", "🇨🇳🇯🇵"];
+        assert_eq!(longest_in::<Bytes, _>(words), Some("🇨🇳🇯🇵"));
+        assert_eq!(longest_in::<Chars, _>(words), Some("nai\u{308}ve"));
+        assert_eq!(longest_in::<Graphemes, _>(words), Some("naïve"));
+        assert_eq!(longest_in::<Chars, _>(Vec::new()), None);
+
+        // 切分结果直接来自迭代器，每个结果都借用自原来的 String
+        let text = String::from("所有权 ownership 借用 borrowing 生命周期 lifetimes");
+        assert_eq!(longest_in::<Chars, _>(text.split_whitespace()), Some("ownership"));
+        assert_eq!(longest_in::<Bytes, _>(text.split_whitespace()), Some("生命周期"));
+    }
+
+    #[test]
+    fn longest_by_key_returns_the_element() {
+        let chapters = vec![
+            Chapter { number: 1, title: String::from("变量与可变性") },
+            Chapter { number: 2, title: String::from("Ownership") },
+            Chapter { number: 3, title: String::from("Résumé of Lifetimes") },
+        ];
+        let number = |chapter: Option<&Chapter>| chapter.map(|c| c.number);
+        assert_eq!(number(longest_by_key::<Bytes, _, _, _>(&chapters, |c| c.title.as_str())), Some(3));
+        assert_eq!(number(longest_by_key::<Chars, _, _, _>(&chapters, |c| c.title.as_str())), Some(3));
+        assert_eq!(number(longest_by_key::<Graphemes, _, _, _>(&chapters[..2], |c| c.title.as_str())), Some(2));
+        assert_eq!(number(longest_by_key::<Bytes, _, _, _>(&chapters[..2], |c| c.title.as_str())), Some(1));
+
+        // 元素也可以是切片本身这样的非定长类型
+        let names: Vec<&str> = vec!["Zoë", "Zoe\u{308}y"];
+        assert_eq!(longest_by_key::<Graphemes, str, _, _>(names.iter().copied(), |s| s), Some("Zoe\u{308}y"));
+    }
+
+    #[test]
+    fn metric_as_type_parameter() {
+        assert_eq!(Longest::<Graphemes>::of("ab", "a\u{301}b\u{301}"), "ab");
+        assert_eq!(Longest::<Chars>::of("ab", "a\u{301}b\u{301}"), "a\u{301}b\u{301}");
+    }
+}
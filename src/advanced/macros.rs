@@ -50,4 +50,25 @@ pub fn macros_example() {
     // - 学习曲线陡峭。
     // - 调试困难。
     // - 可能导致代码难以阅读和理解。
-}
\ No newline at end of file
+}
+
+// 课程注册，见 lesson.rs
+pub struct MacrosLesson;
+
+impl crate::lesson::Lesson for MacrosLesson {
+    fn title(&self) -> &'static str {
+        "Macros"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("macros.rs")
+    }
+
+    fn run(&self) {
+        macros_example()
+    }
+}
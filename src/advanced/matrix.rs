@@ -0,0 +1,510 @@
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+use super::point::{Float, Point};
+
+// 常量泛型矩阵（Const Generics Matrix）
+// `generics.rs` 里的泛型参数都是类型。常量泛型（const generics）让值也能成为参数：
+// `Matrix<T, R, C>` 的行数 R 和列数 C 是类型的一部分，`Matrix<f64, 2, 3>` 和 `Matrix<f64, 3, 2>` 是两个不同的类型。
+// 于是维度检查从运行时挪到了编译期：矩阵乘法要求左边的列数等于右边的行数，
+// 写成 `Mul<Matrix<T, C, K>> for Matrix<T, R, C>` 之后，维度不匹配的乘法根本无法通过编译。
+// 元素存放在 `[[T; C]; R]` 中，大小在编译期已知，不需要堆分配。
+
+// 矩阵运算需要加法、乘法，以及 0 和 1 这两个特殊值（零矩阵和单位矩阵）。
+// 和 point.rs 的 `Float` 一样，标准库没有现成的特征，所以自己定义一个
+pub trait Scalar: Copy + PartialEq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty => $zero:expr, $one:expr);* $(;)?) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = $zero;
+                const ONE: Self = $one;
+            }
+        )*
+    };
+}
+
+impl_scalar!(i32 => 0, 1; i64 => 0, 1; f32 => 0.0, 1.0; f64 => 0.0, 1.0);
+
+/// 维度不匹配的运算在编译期就被拒绝。每个错误写法后面跟着一个只改了一处的正确写法，
+/// 保证错误写法确实是因为维度（或者缺少对应的 impl）才无法编译。
+///
+/// 2x3 乘 2x3：右边必须是 3 行的 `Matrix<i32, 3, K>`：
+///
+/// ```compile_fail,E0308
+/// use rust_lessons::advanced::matrix::Matrix;
+///
+/// let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+/// let _ = a * a;
+/// ```
+///
+/// ```
+/// use rust_lessons::advanced::matrix::Matrix;
+///
+/// let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+/// let _ = a * a.transpose();
+/// ```
+///
+/// 乘积的维度也写在类型里，(2x3) * (3x2) 只能是 2x2：
+///
+/// ```compile_fail,E0308
+/// use rust_lessons::advanced::matrix::Matrix;
+///
+/// let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+/// let b = Matrix::new([[7, 8], [9, 10], [11, 12]]);
+/// let _: Matrix<i32, 3, 3> = a * b;
+/// ```
+///
+/// ```
+/// use rust_lessons::advanced::matrix::Matrix;
+///
+/// let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+/// let b = Matrix::new([[7, 8], [9, 10], [11, 12]]);
+/// let _: Matrix<i32, 2, 2> = a * b;
+/// ```
+///
+/// 2x3 和 3x2 不能相加：
+///
+/// ```compile_fail,E0308
+/// use rust_lessons::advanced::matrix::Matrix;
+///
+/// let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+/// let _ = a + a.transpose();
+/// ```
+///
+/// ```
+/// use rust_lessons::advanced::matrix::Matrix;
+///
+/// let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+/// let _ = a + a.transpose().transpose();
+/// ```
+///
+/// 每一行的长度必须相同：
+///
+/// ```compile_fail,E0308
+/// use rust_lessons::advanced::matrix::Matrix;
+///
+/// let _ = Matrix::new([[1, 2], [3]]);
+/// ```
+///
+/// ```
+/// use rust_lessons::advanced::matrix::Matrix;
+///
+/// let _ = Matrix::new([[1, 2], [3, 4]]);
+/// ```
+///
+/// `identity` 只在方阵 `Matrix<T, N, N>` 上存在：
+///
+/// ```compile_fail,E0599
+/// use rust_lessons::advanced::matrix::Matrix;
+///
+/// let _ = Matrix::<i32, 2, 3>::identity();
+/// ```
+///
+/// ```
+/// use rust_lessons::advanced::matrix::Matrix;
+///
+/// let _ = Matrix::<i32, 3, 3>::identity();
+/// ```
+///
+/// 4x4 没有实现 `determinant`：
+///
+/// ```compile_fail,E0599
+/// use rust_lessons::advanced::matrix::Matrix;
+///
+/// let _ = Matrix::<i32, 4, 4>::identity().determinant();
+/// ```
+///
+/// ```
+/// use rust_lessons::advanced::matrix::Matrix;
+///
+/// let _ = Matrix::<i32, 3, 3>::identity().determinant();
+/// ```
+///
+/// `i32` 没有实现 `Float`，所以没有 `rotation`：
+///
+/// ```compile_fail,E0599
+/// use rust_lessons::advanced::matrix::Matrix;
+///
+/// let _ = Matrix::<i32, 2, 2>::rotation(1);
+/// ```
+///
+/// ```
+/// use rust_lessons::advanced::matrix::Matrix;
+///
+/// let _ = Matrix::<f64, 2, 2>::rotation(1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix<T, const R: usize, const C: usize> {
+    rows: [[T; C]; R],
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+    // 参数的类型 `[[T; C]; R]` 已经决定了 R 和 C，不需要另外写出来：
+    // `Matrix::new([[1, 2, 3], [4, 5, 6]])` 就是 `Matrix<i32, 2, 3>`
+    pub fn new(rows: [[T; C]; R]) -> Matrix<T, R, C> {
+        Matrix { rows }
+    }
+
+    // 用 (行, 列) 计算每个元素
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(mut f: F) -> Matrix<T, R, C> {
+        Matrix { rows: std::array::from_fn(|r| std::array::from_fn(|c| f(r, c))) }
+    }
+
+    // R 和 C 在方法里就是普通的 usize 常量
+    pub fn dimensions(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    // 不会 panic 的访问；用 `m[(r, c)]` 越界时和数组一样会 panic
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.rows.get(row)?.get(col)
+    }
+}
+
+impl<T: Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+    // 返回类型里行列互换了，2x3 转置之后一定是 3x2
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        Matrix::from_fn(|r, c| self.rows[c][r])
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn zero() -> Matrix<T, R, C> {
+        Matrix { rows: [[T::ZERO; C]; R] }
+    }
+}
+
+// 只有方阵才有单位矩阵：这个 impl 块只对 `Matrix<T, N, N>` 生效
+impl<T: Scalar, const N: usize> Matrix<T, N, N> {
+    pub fn identity() -> Matrix<T, N, N> {
+        Matrix::from_fn(|r, c| if r == c { T::ONE } else { T::ZERO })
+    }
+}
+
+// 行列式只为 1x1、2x2、3x3 实现：为具体的 N 分别写 impl 块，
+// 其他大小的矩阵上根本没有 `determinant` 这个方法
+impl<T: Scalar> Matrix<T, 1, 1> {
+    pub fn determinant(&self) -> T {
+        self.rows[0][0]
+    }
+}
+
+impl<T: Scalar> Matrix<T, 2, 2> {
+    pub fn determinant(&self) -> T {
+        let [[a, b], [c, d]] = self.rows;
+        a * d - b * c
+    }
+}
+
+impl<T: Scalar> Matrix<T, 3, 3> {
+    // 按第一行展开：每一项是元素乘以去掉它所在行列后的 2x2 子式
+    pub fn determinant(&self) -> T {
+        let [a, b, c] = self.rows[0];
+        a * self.minor(0, 0).determinant() - b * self.minor(0, 1).determinant() + c * self.minor(0, 2).determinant()
+    }
+
+    // 去掉第 row 行和第 col 列剩下的 2x2 矩阵
+    pub fn minor(&self, row: usize, col: usize) -> Matrix<T, 2, 2> {
+        let skip = |i: usize, removed: usize| if i < removed { i } else { i + 1 };
+        Matrix::from_fn(|r, c| self.rows[skip(r, row)][skip(c, col)])
+    }
+}
+
+// m[(r, c)]
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self.rows[row][col]
+    }
+}
+
+// m[(r, c)] = value
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.rows[row][col]
+    }
+}
+
+// 只有形状相同的矩阵才能相加：两边都是 Matrix<T, R, C>
+impl<T: Scalar, const R: usize, const C: usize> Add for Matrix<T, R, C> {
+    type Output = Matrix<T, R, C>;
+
+    fn add(self, other: Matrix<T, R, C>) -> Matrix<T, R, C> {
+        Matrix::from_fn(|r, c| self.rows[r][c] + other.rows[r][c])
+    }
+}
+
+// (R x C) * (C x K) = (R x K)。右边的行数必须和左边的列数是同一个 C，否则找不到这个实现
+impl<T: Scalar, const R: usize, const C: usize, const K: usize> Mul<Matrix<T, C, K>> for Matrix<T, R, C> {
+    type Output = Matrix<T, R, K>;
+
+    fn mul(self, other: Matrix<T, C, K>) -> Matrix<T, R, K> {
+        Matrix::from_fn(|r, k| (0..C).fold(T::ZERO, |sum, c| sum + self.rows[r][c] * other.rows[c][k]))
+    }
+}
+
+impl<T: fmt::Display, const R: usize, const C: usize> fmt::Display for Matrix<T, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.rows.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let cells: Vec<String> = row.iter().map(|value| value.to_string()).collect();
+            write!(f, "[{}]", cells.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+// 二维变换：和 point.rs 的 Point<T> 配合使用
+// 2x2 矩阵可以表示旋转和缩放，但表示不了平移（原点总是映射到原点）。
+// 平移要用 3x3 的齐次坐标：把点 (x, y) 看成 (x, y, 1)，最后一行固定是 [0, 0, 1]
+impl<T: Scalar> Matrix<T, 2, 2> {
+    pub fn scaling(sx: T, sy: T) -> Matrix<T, 2, 2> {
+        Matrix::new([[sx, T::ZERO], [T::ZERO, sy]])
+    }
+
+    // 放进 3x3 矩阵的左上角，之后就能和平移组合
+    pub fn to_affine(self) -> Matrix<T, 3, 3> {
+        let [[a, b], [c, d]] = self.rows;
+        Matrix::new([[a, b, T::ZERO], [c, d, T::ZERO], [T::ZERO, T::ZERO, T::ONE]])
+    }
+}
+
+// 旋转需要 sin 和 cos，所以只对浮点数可用
+impl<T: Scalar + Float> Matrix<T, 2, 2> {
+    // 逆时针旋转 angle 弧度
+    pub fn rotation(angle: T) -> Matrix<T, 2, 2> {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        Matrix::new([[cos, -sin], [sin, cos]])
+    }
+}
+
+impl<T: Scalar> Matrix<T, 3, 3> {
+    pub fn translation(dx: T, dy: T) -> Matrix<T, 3, 3> {
+        Matrix::new([[T::ONE, T::ZERO, dx], [T::ZERO, T::ONE, dy], [T::ZERO, T::ZERO, T::ONE]])
+    }
+}
+
+// m * p：把点当成 2x1 的列向量
+impl<T: Scalar> Mul<Point<T>> for Matrix<T, 2, 2> {
+    type Output = Point<T>;
+
+    fn mul(self, p: Point<T>) -> Point<T> {
+        let [[a, b], [c, d]] = self.rows;
+        Point::new(a * p.x + b * p.y, c * p.x + d * p.y)
+    }
+}
+
+// 3x3 仿射矩阵乘点：点的第三个坐标是 1，所以第三列直接加上去
+impl<T: Scalar> Mul<Point<T>> for Matrix<T, 3, 3> {
+    type Output = Point<T>;
+
+    fn mul(self, p: Point<T>) -> Point<T> {
+        let [[a, b, tx], [c, d, ty], _] = self.rows;
+        Point::new(a * p.x + b * p.y + tx, c * p.x + d * p.y + ty)
+    }
+}
+
+pub fn matrix_example() {
+    // 维度写在类型里
+    let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    let b = Matrix::new([[7, 8], [9, 10], [11, 12]]);
+    println!("a: {:?}, b: {:?}", a.dimensions(), b.dimensions());
+
+    // (2x3) * (3x2) = 2x2，(3x2) * (2x3) = 3x3
+    let ab: Matrix<i32, 2, 2> = a * b;
+    let ba: Matrix<i32, 3, 3> = b * a;
+    println!("a * b =\n{}", ab);
+    println!("b * a =\n{}", ba);
+
+    // 转置交换维度
+    let at: Matrix<i32, 3, 2> = a.transpose();
+    println!("a^T =\n{}", at);
+
+    // 单位矩阵和零矩阵的大小由上下文推断
+    let same: Matrix<i32, 2, 3> = Matrix::identity() * a;
+    println!("I * a == a: {}", same == a);
+    println!("a + 0 == a: {}", a + Matrix::zero() == a);
+
+    // 下标是 (行, 列) 元组
+    let mut m = Matrix::<f64, 2, 2>::zero();
+    m[(0, 1)] = 2.5;
+    m[(1, 0)] = -1.0;
+    println!("m =\n{}", m);
+    println!("m.get(2, 0) = {:?}", m.get(2, 0));
+    // m[(2, 0)]; // 编译通过，但运行时越界 panic：下标是运行时的值，编译器检查不了
+
+    // 行列式
+    let m3 = Matrix::new([[6, 1, 1], [4, -2, 5], [2, 8, 7]]);
+    println!("det [[3, 8], [4, 6]] = {}", Matrix::new([[3, 8], [4, 6]]).determinant());
+    println!("det of\n{}\n= {}", m3, m3.determinant());
+    // 行列式为 0：行之间线性相关
+    println!("det [[1, 2], [2, 4]] = {}", Matrix::new([[1.0, 2.0], [2.0, 4.0]]).determinant());
+
+    // 和 Point 配合的二维变换
+    let p = Point::new(1.0_f64, 0.0);
+    let quarter = Matrix::rotation(std::f64::consts::FRAC_PI_2);
+    let stretch = Matrix::scaling(2.0, 3.0);
+    let rotated = quarter * p;
+    println!("rotate {} by 90 degrees: ({:.3}, {:.3})", p, rotated.x, rotated.y);
+    println!("scale (1, 1) by (2, 3): {}", stretch * Point::new(1.0, 1.0));
+    // 变换的组合就是矩阵乘法：先缩放再旋转，写成 rotation * scaling
+    let combined = (quarter * stretch) * p;
+    println!("scale then rotate {}: ({:.3}, {:.3})", p, combined.x, combined.y);
+    // 平移要用 3x3 仿射矩阵：先旋转 90 度再向右平移 10
+    let moved = Matrix::translation(10.0, 0.0) * quarter.to_affine();
+    let placed = moved * p;
+    println!("rotate {} by 90 degrees, then move right by 10: ({:.3}, {:.3})", p, placed.x, placed.y);
+    // 整数也能用缩放和平移，只有旋转需要浮点数
+    let grid = Matrix::translation(1, 2) * Matrix::scaling(2, 2).to_affine();
+    println!("scale (3, 4) by 2, then move by (1, 2): {}", grid * Point::new(3, 4));
+
+    // 编译器拒绝的写法，`Matrix` 的文档测试（`compile_fail`）会在 `cargo test` 时确认它们仍然无法编译
+    // a * a; // 错误！2x3 乘 2x3：右边必须是 3 行的 Matrix<i32, 3, K>（E0308）
+    // let wrong: Matrix<i32, 3, 3> = a * b; // 错误！a * b 的类型是 Matrix<i32, 2, 2>（E0308）
+    // a + at; // 错误！2x3 和 3x2 不能相加（E0308）
+    // Matrix::<i32, 2, 3>::identity(); // 错误！identity 只在方阵 Matrix<T, N, N> 上存在（E0599）
+    // Matrix::<i32, 4, 4>::identity().determinant(); // 错误！4x4 没有实现 determinant（E0599）
+    // Matrix::new([[1, 2], [3]]); // 错误！每一行的长度必须相同（E0308）
+    // Matrix::<i32, 2, 2>::rotation(1); // 错误！i32 没有实现 Float，所以没有 rotation（E0599）
+}
+
+// 课程注册，见 lesson.rs
+pub struct MatrixLesson;
+
+impl crate::lesson::Lesson for MatrixLesson {
+    fn title(&self) -> &'static str {
+        "Const Generics Matrix"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("matrix.rs")
+    }
+
+    fn run(&self) {
+        matrix_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(p: Point<f64>, q: Point<f64>) -> bool {
+        (p - q).length() < 1e-9
+    }
+
+    #[test]
+    fn multiplication_follows_dimensions() {
+        let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::new([[7, 8], [9, 10], [11, 12]]);
+        assert_eq!(a.dimensions(), (2, 3));
+        assert_eq!(b.dimensions(), (3, 2));
+        let ab: Matrix<i32, 2, 2> = a * b;
+        assert_eq!(ab, Matrix::new([[58, 64], [139, 154]]));
+        let ba: Matrix<i32, 3, 3> = b * a;
+        assert_eq!(ba[(2, 2)], 11 * 3 + 12 * 6);
+    }
+
+    #[test]
+    fn transpose_swaps_dimensions() {
+        let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::new([[7, 8], [9, 10], [11, 12]]);
+        let at: Matrix<i32, 3, 2> = a.transpose();
+        assert_eq!(at, Matrix::new([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(at.transpose(), a);
+        // (AB)^T = B^T A^T
+        assert_eq!((a * b).transpose(), b.transpose() * a.transpose());
+    }
+
+    #[test]
+    fn identity_and_zero() {
+        let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(Matrix::identity() * a, a);
+        assert_eq!(a * Matrix::identity(), a);
+        assert_eq!(Matrix::<i32, 3, 3>::identity().transpose(), Matrix::identity());
+        assert_eq!(a + Matrix::zero(), a);
+        assert_eq!(a * Matrix::<i32, 3, 4>::zero(), Matrix::zero());
+    }
+
+    #[test]
+    fn indexing() {
+        let mut m = Matrix::<f64, 2, 2>::zero();
+        m[(0, 1)] = 2.5;
+        m[(1, 0)] = -1.0;
+        assert_eq!(m[(0, 1)], 2.5);
+        assert_eq!(m.get(1, 0), Some(&-1.0));
+        assert_eq!(m.get(2, 0), None);
+        assert_eq!(m.get(0, 2), None);
+        assert_eq!(m.to_string(), "[0, 2.5]\n[-1, 0]");
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds_panics() {
+        let m = Matrix::<i32, 2, 2>::zero();
+        let row = m.dimensions().0;
+        let _ = m[(row, 0)];
+    }
+
+    #[test]
+    fn determinants() {
+        assert_eq!(Matrix::new([[7]]).determinant(), 7);
+        assert_eq!(Matrix::new([[3, 8], [4, 6]]).determinant(), -14);
+        let m3 = Matrix::new([[6, 1, 1], [4, -2, 5], [2, 8, 7]]);
+        assert_eq!(m3.determinant(), -306);
+        assert_eq!(m3.minor(1, 1), Matrix::new([[6, 1], [2, 7]]));
+        assert_eq!(m3.transpose().determinant(), m3.determinant());
+        assert_eq!(Matrix::<i64, 3, 3>::identity().determinant(), 1);
+        // det(AB) = det(A) det(B)
+        let n3 = Matrix::new([[2, 0, 1], [1, 3, 2], [1, 1, 1]]);
+        assert_eq!((m3 * n3).determinant(), m3.determinant() * n3.determinant());
+        // 行之间线性相关
+        assert_eq!(Matrix::new([[1.0, 2.0], [2.0, 4.0]]).determinant(), 0.0);
+    }
+
+    #[test]
+    fn rotation_and_scaling() {
+        let p = Point::new(1.0_f64, 0.0);
+        let quarter = Matrix::rotation(std::f64::consts::FRAC_PI_2);
+        assert!(close(quarter * p, Point::new(0.0, 1.0)));
+        // 旋转不改变长度，行列式是 1
+        let turn = Matrix::rotation(0.75_f64);
+        assert!(((turn * Point::new(3.0, 4.0)).length() - 5.0).abs() < 1e-9);
+        assert!((turn.determinant() - 1.0).abs() < 1e-12);
+        // 旋转矩阵的逆就是它的转置
+        assert!(close(turn.transpose() * (turn * p), p));
+        // 缩放的行列式是面积的缩放比例
+        let stretch = Matrix::scaling(2.0, 3.0);
+        assert_eq!(stretch * Point::new(1.0, 1.0), Point::new(2.0, 3.0));
+        assert_eq!(stretch.determinant(), 6.0);
+        let combined = quarter * stretch;
+        assert!(close(combined * Point::new(1.0, 1.0), quarter * (stretch * Point::new(1.0, 1.0))));
+        assert!(close(combined * p, Point::new(0.0, 2.0)));
+        // f32 同样可以
+        let half_turn = Matrix::rotation(std::f32::consts::PI);
+        assert!((half_turn * Point::new(1.0_f32, 0.0)).distance(Point::new(-1.0, 0.0)) < 1e-6);
+    }
+
+    #[test]
+    fn affine_translation() {
+        let p = Point::new(1.0_f64, 0.0);
+        let quarter = Matrix::rotation(std::f64::consts::FRAC_PI_2);
+        let moved = Matrix::translation(10.0, 0.0) * quarter.to_affine();
+        assert!(close(moved * p, Point::new(10.0, 1.0)));
+        assert!(close(moved * Point::new(0.0, 0.0), Point::new(10.0, 0.0)));
+        let grid = Matrix::translation(1, 2) * Matrix::scaling(2, 2).to_affine();
+        assert_eq!(grid * Point::new(3, 4), Point::new(7, 10));
+    }
+}
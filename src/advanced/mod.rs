@@ -3,4 +3,16 @@ pub mod traits;
 pub mod generics;
 pub mod closures;
 pub mod threads;
-pub mod macros;
\ No newline at end of file
+pub mod macros;
+pub mod point;
+pub mod option_result;
+pub mod excerpt;
+pub mod longest;
+pub mod config;
+pub mod arena;
+pub mod feed;
+pub mod blanket;
+pub mod std_traits;
+pub mod selection;
+pub mod containers;
+pub mod matrix;
\ No newline at end of file
@@ -0,0 +1,664 @@
+use std::fmt;
+
+// 自己实现 Option 和 Result（Custom Option and Result）
+// `generics.rs` 里声明了同名的 `enum Option<T>` 和 `enum Result<T, E>`，但从来没有用过。
+// 这里把它们写成完整的实现：常用的组合子（combinator）、和标准库类型的互相转换、迭代器支持。
+// 标准库的版本并没有什么魔法，绝大部分方法都只是一个 `match`。
+//
+// 这个模块里的 `Option` 和 `Result` 遮蔽了预导入（prelude）中的同名类型，
+// 但 `Some`、`None`、`Ok`、`Err` 这几个变体名仍然指向标准库。
+// 所以 `Option::Some(1)` 是我们的类型，`Some(1)` 是标准库的，两者在代码里一眼就能区分。
+// 为了能直接写标准库的类型，再给它们起两个别名：
+type StdOption<T> = std::option::Option<T>;
+type StdResult<T, E> = std::result::Result<T, E>;
+
+// 变体的声明顺序决定了派生出来的 `PartialOrd`：先声明的更小。
+// 标准库里 `None < Some(_)`，所以这里把 `None` 放在前面。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Option<T> {
+    #[default]
+    None,
+    Some(T),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Result<T, E> {
+    Ok(T),
+    Err(E),
+}
+
+impl<T> Option<T> {
+    pub fn is_some(&self) -> bool {
+        matches!(self, Option::Some(_))
+    }
+
+    pub fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
+    // `&Option<T>` -> `Option<&T>`：不获取所有权就能使用下面那些消耗 self 的方法
+    pub fn as_ref(&self) -> Option<&T> {
+        match self {
+            Option::Some(value) => Option::Some(value),
+            Option::None => Option::None,
+        }
+    }
+
+    pub fn as_mut(&mut self) -> Option<&mut T> {
+        match self {
+            Option::Some(value) => Option::Some(value),
+            Option::None => Option::None,
+        }
+    }
+
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Option<U> {
+        match self {
+            Option::Some(value) => Option::Some(f(value)),
+            Option::None => Option::None,
+        }
+    }
+
+    pub fn map_or<U, F: FnOnce(T) -> U>(self, default: U, f: F) -> U {
+        match self {
+            Option::Some(value) => f(value),
+            Option::None => default,
+        }
+    }
+
+    pub fn map_or_else<U, D: FnOnce() -> U, F: FnOnce(T) -> U>(self, default: D, f: F) -> U {
+        match self {
+            Option::Some(value) => f(value),
+            Option::None => default(),
+        }
+    }
+
+    pub fn and_then<U, F: FnOnce(T) -> Option<U>>(self, f: F) -> Option<U> {
+        match self {
+            Option::Some(value) => f(value),
+            Option::None => Option::None,
+        }
+    }
+
+    pub fn and<U>(self, other: Option<U>) -> Option<U> {
+        self.and_then(|_| other)
+    }
+
+    pub fn or(self, other: Option<T>) -> Option<T> {
+        self.or_else(|| other)
+    }
+
+    pub fn or_else<F: FnOnce() -> Option<T>>(self, f: F) -> Option<T> {
+        match self {
+            Option::Some(value) => Option::Some(value),
+            Option::None => f(),
+        }
+    }
+
+    // 恰好有一个是 Some 时返回它
+    pub fn xor(self, other: Option<T>) -> Option<T> {
+        match (self, other) {
+            (Option::Some(value), Option::None) | (Option::None, Option::Some(value)) => Option::Some(value),
+            _ => Option::None,
+        }
+    }
+
+    pub fn filter<P: FnOnce(&T) -> bool>(self, predicate: P) -> Option<T> {
+        match self {
+            Option::Some(value) if predicate(&value) => Option::Some(value),
+            _ => Option::None,
+        }
+    }
+
+    pub fn zip<U>(self, other: Option<U>) -> Option<(T, U)> {
+        match (self, other) {
+            (Option::Some(a), Option::Some(b)) => Option::Some((a, b)),
+            _ => Option::None,
+        }
+    }
+
+    // 和标准库一样的 panic 信息
+    pub fn unwrap(self) -> T {
+        match self {
+            Option::Some(value) => value,
+            Option::None => panic!("called `Option::unwrap()` on a `None` value"),
+        }
+    }
+
+    pub fn expect(self, message: &str) -> T {
+        match self {
+            Option::Some(value) => value,
+            Option::None => panic!("{}", message),
+        }
+    }
+
+    pub fn unwrap_or(self, default: T) -> T {
+        self.unwrap_or_else(|| default)
+    }
+
+    pub fn unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {
+        match self {
+            Option::Some(value) => value,
+            Option::None => f(),
+        }
+    }
+
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        self.unwrap_or_else(T::default)
+    }
+
+    pub fn ok_or<E>(self, error: E) -> Result<T, E> {
+        self.ok_or_else(|| error)
+    }
+
+    pub fn ok_or_else<E, F: FnOnce() -> E>(self, f: F) -> Result<T, E> {
+        match self {
+            Option::Some(value) => Result::Ok(value),
+            Option::None => Result::Err(f()),
+        }
+    }
+
+    // 把值拿走，原地留下 None
+    pub fn take(&mut self) -> Option<T> {
+        std::mem::take(self)
+    }
+
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        std::mem::replace(self, Option::Some(value))
+    }
+
+    pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, f: F) -> &mut T {
+        if self.is_none() {
+            *self = Option::Some(f());
+        }
+        match self {
+            Option::Some(value) => value,
+            Option::None => unreachable!("just inserted a value"),
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { inner: self.as_ref() }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { inner: self.as_mut() }
+    }
+}
+
+impl<T> Option<Option<T>> {
+    pub fn flatten(self) -> Option<T> {
+        self.and_then(|inner| inner)
+    }
+}
+
+impl<T, E> Option<Result<T, E>> {
+    // Option<Result<T, E>> -> Result<Option<T>, E>
+    pub fn transpose(self) -> Result<Option<T>, E> {
+        match self {
+            Option::Some(Result::Ok(value)) => Result::Ok(Option::Some(value)),
+            Option::Some(Result::Err(error)) => Result::Err(error),
+            Option::None => Result::Ok(Option::None),
+        }
+    }
+}
+
+impl<T, E> Result<T, E> {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Result::Ok(_))
+    }
+
+    pub fn is_err(&self) -> bool {
+        !self.is_ok()
+    }
+
+    // 丢掉错误，只留下成功的值
+    pub fn ok(self) -> Option<T> {
+        match self {
+            Result::Ok(value) => Option::Some(value),
+            Result::Err(_) => Option::None,
+        }
+    }
+
+    pub fn err(self) -> Option<E> {
+        match self {
+            Result::Ok(_) => Option::None,
+            Result::Err(error) => Option::Some(error),
+        }
+    }
+
+    pub fn as_ref(&self) -> Result<&T, &E> {
+        match self {
+            Result::Ok(value) => Result::Ok(value),
+            Result::Err(error) => Result::Err(error),
+        }
+    }
+
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Result<U, E> {
+        match self {
+            Result::Ok(value) => Result::Ok(f(value)),
+            Result::Err(error) => Result::Err(error),
+        }
+    }
+
+    pub fn map_err<G, F: FnOnce(E) -> G>(self, f: F) -> Result<T, G> {
+        match self {
+            Result::Ok(value) => Result::Ok(value),
+            Result::Err(error) => Result::Err(f(error)),
+        }
+    }
+
+    pub fn map_or<U, F: FnOnce(T) -> U>(self, default: U, f: F) -> U {
+        match self {
+            Result::Ok(value) => f(value),
+            Result::Err(_) => default,
+        }
+    }
+
+    pub fn and_then<U, F: FnOnce(T) -> Result<U, E>>(self, f: F) -> Result<U, E> {
+        match self {
+            Result::Ok(value) => f(value),
+            Result::Err(error) => Result::Err(error),
+        }
+    }
+
+    pub fn and<U>(self, other: Result<U, E>) -> Result<U, E> {
+        self.and_then(|_| other)
+    }
+
+    pub fn or<G>(self, other: Result<T, G>) -> Result<T, G> {
+        self.or_else(|_| other)
+    }
+
+    pub fn or_else<G, F: FnOnce(E) -> Result<T, G>>(self, f: F) -> Result<T, G> {
+        match self {
+            Result::Ok(value) => Result::Ok(value),
+            Result::Err(error) => f(error),
+        }
+    }
+
+    pub fn unwrap(self) -> T
+    where
+        E: fmt::Debug,
+    {
+        match self {
+            Result::Ok(value) => value,
+            Result::Err(error) => panic!("called `Result::unwrap()` on an `Err` value: {:?}", error),
+        }
+    }
+
+    pub fn expect(self, message: &str) -> T
+    where
+        E: fmt::Debug,
+    {
+        match self {
+            Result::Ok(value) => value,
+            Result::Err(error) => panic!("{}: {:?}", message, error),
+        }
+    }
+
+    pub fn unwrap_err(self) -> E
+    where
+        T: fmt::Debug,
+    {
+        match self {
+            Result::Ok(value) => panic!("called `Result::unwrap_err()` on an `Ok` value: {:?}", value),
+            Result::Err(error) => error,
+        }
+    }
+
+    pub fn unwrap_or(self, default: T) -> T {
+        self.unwrap_or_else(|_| default)
+    }
+
+    pub fn unwrap_or_else<F: FnOnce(E) -> T>(self, f: F) -> T {
+        match self {
+            Result::Ok(value) => value,
+            Result::Err(error) => f(error),
+        }
+    }
+
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        self.unwrap_or_else(|_| T::default())
+    }
+
+    // 成功时产生一个值，失败时什么都不产生
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { inner: self.as_ref().ok() }
+    }
+}
+
+// `?` 运算符依赖标准库中尚未稳定的 `Try` 特征，自己的类型没法直接用 `?`。
+// 所以在边界上和标准库类型互相转换：`StdResult::from(ours)?`，或者用 `.into()`
+impl<T> From<StdOption<T>> for Option<T> {
+    fn from(option: StdOption<T>) -> Option<T> {
+        match option {
+            Some(value) => Option::Some(value),
+            None => Option::None,
+        }
+    }
+}
+
+impl<T> From<Option<T>> for StdOption<T> {
+    fn from(option: Option<T>) -> StdOption<T> {
+        match option {
+            Option::Some(value) => Some(value),
+            Option::None => None,
+        }
+    }
+}
+
+impl<T, E> From<StdResult<T, E>> for Result<T, E> {
+    fn from(result: StdResult<T, E>) -> Result<T, E> {
+        match result {
+            Ok(value) => Result::Ok(value),
+            Err(error) => Result::Err(error),
+        }
+    }
+}
+
+impl<T, E> From<Result<T, E>> for StdResult<T, E> {
+    fn from(result: Result<T, E>) -> StdResult<T, E> {
+        match result {
+            Result::Ok(value) => Ok(value),
+            Result::Err(error) => Err(error),
+        }
+    }
+}
+
+// 迭代器：Option 和 Result 都可以看作最多包含一个元素的集合
+pub struct Iter<'a, T> {
+    inner: Option<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> StdOption<&'a T> {
+        self.inner.take().into()
+    }
+}
+
+pub struct IterMut<'a, T> {
+    inner: Option<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> StdOption<&'a mut T> {
+        self.inner.take().into()
+    }
+}
+
+pub struct IntoIter<T> {
+    inner: Option<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> StdOption<T> {
+        self.inner.take().into()
+    }
+}
+
+impl<T> IntoIterator for Option<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { inner: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Option<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T, E> IntoIterator for Result<T, E> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { inner: self.ok() }
+    }
+}
+
+// `collect()` 成 Option：所有元素都是 Some 时得到 Some(集合)，遇到第一个 None 就得到 None。
+// 直接借用标准库的实现，再把结果转换过来
+impl<A, V: FromIterator<A>> FromIterator<Option<A>> for Option<V> {
+    fn from_iter<I: IntoIterator<Item = Option<A>>>(iter: I) -> Option<V> {
+        iter.into_iter().map(StdOption::from).collect::<StdOption<V>>().into()
+    }
+}
+
+impl<A, E, V: FromIterator<A>> FromIterator<Result<A, E>> for Result<V, E> {
+    fn from_iter<I: IntoIterator<Item = Result<A, E>>>(iter: I) -> Result<V, E> {
+        iter.into_iter().map(StdResult::from).collect::<StdResult<V, E>>().into()
+    }
+}
+
+// 返回标准库 Result 的函数里，把我们的 Result 转换过去就能用 `?`
+fn parse_both(a: &str, b: &str) -> StdResult<i32, std::num::ParseIntError> {
+    let a: Result<i32, _> = a.parse::<i32>().into();
+    let b: Result<i32, _> = b.parse::<i32>().into();
+    let sum = StdResult::from(a)? + StdResult::from(b)?;
+    Ok(sum)
+}
+
+pub fn option_result_example() {
+    let half = |n: i32| if n % 2 == 0 { Option::Some(n / 2) } else { Option::None };
+    let parse = |s: &str| -> Result<i32, String> { StdResult::from(s.parse::<i32>()).map_err(|e| e.to_string()).into() };
+
+    // 组合子和标准库的用法一样，只是变体要写全 `Option::Some`
+    println!("half(10) = {:?}, half(3) = {:?}", half(10), half(3));
+    println!("Some(8).and_then(half) = {:?}", Option::Some(8).and_then(half));
+    println!("Some(4).filter(> 3).map(* 10) = {:?}", Option::Some(4).filter(|n| *n > 3).map(|n| n * 10));
+    println!("None.unwrap_or(0) = {}, None < Some(0) = {}", Option::None.unwrap_or(0), Option::None < Option::Some(0));
+    println!("parse(\"42\") = {:?}, parse(\"x\") = {:?}", parse("42"), parse("x"));
+    println!("parse(\"x\").unwrap_or_default() = {}", parse("x").unwrap_or_default());
+
+    // 原地修改的方法
+    let mut name = Option::Some(String::from("a"));
+    println!("take = {:?}, left = {:?}", name.take(), name);
+    name.get_or_insert_with(String::new).push('b');
+    println!("get_or_insert_with = {:?}", name);
+
+    // collect：和标准库一样在第一个 None / Err 处停下
+    let all: Option<Vec<i32>> = [2, 4, 6].into_iter().map(half).collect();
+    let some_missing: Option<Vec<i32>> = [2, 3, 6].into_iter().map(half).collect();
+    let failed: Result<Vec<i32>, String> = ["1", "x", "y"].into_iter().map(parse).collect();
+    println!("collect: {:?}, {:?}, {:?}", all, some_missing, failed);
+
+    // 迭代器适配器也能直接用：flatten 跳过 None
+    let values: Vec<i32> = [Option::Some(1), Option::None, Option::Some(3)].into_iter().flatten().collect();
+    println!("flatten: {:?}", values);
+
+    // 通过转换和 `?` 配合
+    println!("parse_both: {:?}, {:?}", parse_both("20", "22"), parse_both("20", "x"));
+}
+
+// 课程注册，见 lesson.rs
+pub struct OptionResultLesson;
+
+impl crate::lesson::Lesson for OptionResultLesson {
+    fn title(&self) -> &'static str {
+        "Option and Result"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("option_result.rs")
+    }
+
+    fn run(&self) {
+        option_result_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 检查我们的实现和标准库在同一个输入上的结果完全一致
+    fn same<T: PartialEq + fmt::Debug>(ours: impl Into<StdOption<T>>, theirs: StdOption<T>) {
+        assert_eq!(ours.into(), theirs);
+    }
+
+    fn same_result<T: PartialEq + fmt::Debug, E: PartialEq + fmt::Debug>(
+        ours: impl Into<StdResult<T, E>>,
+        theirs: StdResult<T, E>,
+    ) {
+        assert_eq!(ours.into(), theirs);
+    }
+
+    fn half(n: i32) -> Option<i32> {
+        if n % 2 == 0 { Option::Some(n / 2) } else { Option::None }
+    }
+
+    fn std_half(n: i32) -> StdOption<i32> {
+        if n % 2 == 0 { Some(n / 2) } else { None }
+    }
+
+    fn parse(s: &str) -> Result<i32, String> {
+        StdResult::from(s.parse::<i32>()).map_err(|e| e.to_string()).into()
+    }
+
+    fn std_parse(s: &str) -> StdResult<i32, String> {
+        s.parse::<i32>().map_err(|e| e.to_string())
+    }
+
+    // 每个组合子都拿 Some 和 None 两种输入和标准库比较
+    #[test]
+    fn option_combinators_match_std() {
+        for (ours, theirs) in [(Option::Some(4), Some(4)), (Option::Some(3), Some(3)), (Option::None, None)] {
+            assert_eq!(ours.is_some(), theirs.is_some());
+            assert_eq!(ours.is_none(), theirs.is_none());
+            same(ours.map(|n| n * 10), theirs.map(|n| n * 10));
+            assert_eq!(ours.map_or(-1, |n| n + 1), theirs.map_or(-1, |n| n + 1));
+            assert_eq!(ours.map_or_else(|| -1, |n| n + 1), theirs.map_or_else(|| -1, |n| n + 1));
+            same(ours.and_then(half), theirs.and_then(std_half));
+            same(ours.and(Option::Some("x")), theirs.and(Some("x")));
+            same(ours.or(Option::Some(9)), theirs.or(Some(9)));
+            same(ours.or_else(|| half(8)), theirs.or_else(|| std_half(8)));
+            same(ours.xor(Option::Some(9)), theirs.xor(Some(9)));
+            same(ours.xor(Option::None), theirs.xor(None));
+            same(ours.filter(|n| *n > 3), theirs.filter(|n| *n > 3));
+            same(ours.zip(Option::Some('z')), theirs.zip(Some('z')));
+            assert_eq!(ours.unwrap_or(0), theirs.unwrap_or(0));
+            assert_eq!(ours.unwrap_or_else(|| half(14).unwrap()), theirs.unwrap_or_else(|| std_half(14).unwrap()));
+            assert_eq!(ours.unwrap_or_default(), theirs.unwrap_or_default());
+            same_result(ours.ok_or("missing"), theirs.ok_or("missing"));
+            same_result(ours.ok_or_else(|| String::from("missing")), theirs.ok_or_else(|| String::from("missing")));
+            same(ours.as_ref().map(|n| *n), theirs.as_ref().copied());
+            assert_eq!(ours.iter().collect::<Vec<_>>(), theirs.iter().collect::<Vec<_>>());
+            assert_eq!(ours.into_iter().count(), theirs.into_iter().count());
+            same(Option::Some(ours).flatten(), Some(theirs).flatten());
+            assert_eq!(ours < Option::Some(0), theirs < Some(0));
+            assert_eq!(ours.cmp(&Option::None), theirs.cmp(&None));
+        }
+        assert_eq!(Option::Some(4).unwrap(), 4);
+        assert_eq!(Option::<i32>::default(), Option::None);
+    }
+
+    #[test]
+    fn option_in_place_methods_match_std() {
+        let mut ours = Option::Some(String::from("a"));
+        let mut theirs = Some(String::from("a"));
+        same(ours.take(), theirs.take());
+        same(ours.clone(), theirs.clone());
+        same(ours.replace(String::from("b")), theirs.replace(String::from("b")));
+        if let Option::Some(s) = ours.as_mut() {
+            s.push('!');
+        }
+        for s in ours.iter_mut() {
+            s.push('?');
+        }
+        if let Some(s) = theirs.as_mut() {
+            s.push_str("!?");
+        }
+        same(ours.clone(), theirs.clone());
+        let mut empty: Option<Vec<i32>> = Option::None;
+        empty.get_or_insert_with(Vec::new).push(1);
+        same(empty, Some(vec![1]));
+    }
+
+    #[test]
+    fn result_combinators_match_std() {
+        for input in ["42", "-7", "x"] {
+            let (ours, theirs) = (parse(input), std_parse(input));
+            assert_eq!(ours.is_ok(), theirs.is_ok());
+            assert_eq!(ours.is_err(), theirs.is_err());
+            same(ours.clone().ok(), theirs.clone().ok());
+            same(ours.clone().err(), theirs.clone().err());
+            same_result(ours.clone().map(|n| n * 2), theirs.clone().map(|n| n * 2));
+            same_result(ours.clone().map_err(|e| e.len()), theirs.clone().map_err(|e| e.len()));
+            assert_eq!(ours.clone().map_or(0, |n| n + 1), theirs.clone().map_or(0, |n| n + 1));
+            same_result(
+                ours.clone().and_then(|n| if n > 0 { Result::Ok(n) } else { Result::Err(String::from("negative")) }),
+                theirs.clone().and_then(|n| if n > 0 { Ok(n) } else { Err(String::from("negative")) }),
+            );
+            same_result(ours.clone().and(Result::Ok('c')), theirs.clone().and(Ok('c')));
+            same_result(ours.clone().or::<()>(Result::Ok(0)), theirs.clone().or::<()>(Ok(0)));
+            same_result(
+                ours.clone().or_else(|e| if e.is_empty() { Result::Ok(0) } else { Result::Err(e.len()) }),
+                theirs.clone().or_else(|e| if e.is_empty() { Ok(0) } else { Err(e.len()) }),
+            );
+            assert_eq!(ours.clone().unwrap_or(0), theirs.clone().unwrap_or(0));
+            assert_eq!(ours.clone().unwrap_or_else(|e| e.len() as i32), theirs.clone().unwrap_or_else(|e| e.len() as i32));
+            assert_eq!(ours.clone().unwrap_or_default(), theirs.clone().unwrap_or_default());
+            assert_eq!(ours.iter().collect::<Vec<_>>(), theirs.iter().collect::<Vec<_>>());
+            assert_eq!(ours.clone().into_iter().sum::<i32>(), theirs.clone().into_iter().sum::<i32>());
+            same_result(
+                Option::Some(ours.clone()).transpose().map(StdOption::from),
+                Some(theirs.clone()).transpose(),
+            );
+            assert_eq!(ours.as_ref().is_ok(), theirs.as_ref().is_ok());
+        }
+        assert_eq!(parse("42").unwrap(), 42);
+        assert_eq!(parse("42").expect("a number"), 42);
+        assert_eq!(parse("x").unwrap_err(), "invalid digit found in string");
+        assert_eq!(Option::Some(1).expect("present"), 1);
+    }
+
+    // collect：和标准库一样在第一个 None / Err 处停下
+    #[test]
+    fn collect_stops_at_first_failure() {
+        let all: Option<Vec<i32>> = [2, 4, 6].into_iter().map(half).collect();
+        let some_missing: Option<Vec<i32>> = [2, 3, 6].into_iter().map(half).collect();
+        same(all, Some(vec![1, 2, 3]));
+        same(some_missing, None::<Vec<i32>>);
+        let parsed: Result<Vec<i32>, String> = ["1", "2", "3"].into_iter().map(parse).collect();
+        same_result(parsed, Ok(vec![1, 2, 3]));
+        let failed: Result<Vec<i32>, String> = ["1", "x", "y"].into_iter().map(parse).collect();
+        same_result(failed, Err(String::from("invalid digit found in string")));
+    }
+
+    // 迭代器适配器也能直接用：flatten 跳过 None
+    #[test]
+    fn flatten_skips_none() {
+        let values: Vec<i32> = [Option::Some(1), Option::None, Option::Some(3)].into_iter().flatten().collect();
+        assert_eq!(values, vec![1, 3]);
+    }
+
+    // 通过转换和 `?` 配合
+    #[test]
+    fn question_mark_through_conversion() {
+        assert_eq!(parse_both("20", "22"), Ok(42));
+        assert!(parse_both("20", "x").is_err());
+    }
+}
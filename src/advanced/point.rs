@@ -0,0 +1,320 @@
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+// 泛型点与运算符重载（Operator Overloading）
+// `generics.rs` 中的 `Point<T>` 只有 `x()` 和仅限 `f32` 的 `distance_from_origin`。
+// 这里把它扩展成一个二维点/向量类型：通过实现 `std::ops` 中的特征，
+// `+`、`-`、`*`、一元 `-` 都可以直接用在 `Point` 上。
+// 每个实现只要求 `T` 具备它真正需要的能力，例如 `Add` 只要求 `T: Add`。
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub fn new(x: T, y: T) -> Point<T> {
+        Point { x, y }
+    }
+
+    pub fn x(&self) -> &T {
+        &self.x
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+// p + q
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+// p - q
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+// p * k：和标量相乘
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, k: T) -> Point<T> {
+        Point::new(self.x * k, self.y * k)
+    }
+}
+
+// k * p：孤儿规则不允许写 `impl<T> Mul<Point<T>> for T`，
+// 因为 `T` 可能是任何外部类型，所以只能为具体的标量类型逐个实现。
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {
+        $(
+            impl Mul<Point<$t>> for $t {
+                type Output = Point<$t>;
+
+                fn mul(self, p: Point<$t>) -> Point<$t> {
+                    p * self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_mul!(i32, i64, f32, f64);
+
+// -p
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+// 点积对整数和浮点数都有意义
+impl<T: Add<Output = T> + Mul<Output = T> + Copy> Point<T> {
+    pub fn dot(self, other: Point<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+}
+
+// 数值特征（Numeric Trait）
+// 距离需要开平方，只有浮点数才有 `sqrt`。标准库没有一个同时代表 f32 和 f64 的特征，
+// 所以我们自己定义一个，再为两种浮点类型实现它。
+// matrix.rs 的旋转矩阵还需要 `sin` 和 `cos`，也放在这个特征里。
+pub trait Float:
+    Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl Float for $t {
+                fn sqrt(self) -> Self {
+                    <$t>::sqrt(self)
+                }
+
+                fn sin(self) -> Self {
+                    <$t>::sin(self)
+                }
+
+                fn cos(self) -> Self {
+                    <$t>::cos(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+impl<T: Float> Point<T> {
+    pub fn length(self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn distance(self, other: Point<T>) -> T {
+        (self - other).length()
+    }
+
+    // 原来只有 Point<f32> 才有的方法，现在 f32 和 f64 都可以用
+    pub fn distance_from_origin(&self) -> T {
+        self.length()
+    }
+}
+
+// 整数点 -> 浮点点：只为不会丢失精度的组合实现 `From`。
+// 例如 i32 -> f64 是精确的，而 i32 -> f32 不是（f32 只有 24 位尾数）。
+macro_rules! impl_from_int {
+    ($($from:ty => $to:ty),*) => {
+        $(
+            impl From<Point<$from>> for Point<$to> {
+                fn from(p: Point<$from>) -> Point<$to> {
+                    Point::new(<$to>::from(p.x), <$to>::from(p.y))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(
+    i8 => f32, i16 => f32, u8 => f32, u16 => f32,
+    i8 => f64, i16 => f64, i32 => f64, u8 => f64, u16 => f64, u32 => f64
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointConversionError {
+    NotFinite,
+    NotIntegral,
+    OutOfRange,
+}
+
+impl fmt::Display for PointConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PointConversionError::NotFinite => write!(f, "coordinate is NaN or infinite"),
+            PointConversionError::NotIntegral => write!(f, "coordinate has a fractional part"),
+            PointConversionError::OutOfRange => write!(f, "coordinate is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for PointConversionError {}
+
+// 浮点点 -> 整数点可能失败，所以实现的是 `TryFrom` 而不是 `From`：
+// 坐标必须是有限的整数值，并且在目标类型的范围内。
+macro_rules! impl_try_from_float {
+    ($($from:ty => $to:ty),*) => {
+        $(
+            impl TryFrom<Point<$from>> for Point<$to> {
+                type Error = PointConversionError;
+
+                fn try_from(p: Point<$from>) -> Result<Point<$to>, PointConversionError> {
+                    let convert = |v: $from| {
+                        if !v.is_finite() {
+                            Err(PointConversionError::NotFinite)
+                        } else if v.fract() != 0.0 {
+                            Err(PointConversionError::NotIntegral)
+                        // MAX 转成浮点数时会向上取整成 2^n，所以用 -MIN（正好是 2^n）作为开区间上界
+                        } else if v < <$to>::MIN as $from || v >= -(<$to>::MIN as $from) {
+                            Err(PointConversionError::OutOfRange)
+                        } else {
+                            Ok(v as $to)
+                        }
+                    };
+                    Ok(Point::new(convert(p.x)?, convert(p.y)?))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_float!(f32 => i32, f64 => i32, f64 => i64);
+
+pub fn point_example() {
+    // 运算符重载
+    let p = Point::new(3, 4);
+    let q = Point::new(1, -2);
+    println!("p = {}, q = {}", p, q);
+    println!("p + q = {}, p - q = {}, p * 2 = {}, 2 * p = {}, -p = {}", p + q, p - q, p * 2, 2 * p, -p);
+    println!("p . q = {}", p.dot(q));
+
+    // 浮点运算：同一份代码同时适用于 f32 和 f64
+    let a = Point::new(3.0_f64, 4.0);
+    let c = Point::new(6.0_f32, 8.0);
+    println!("|{}| = {}, |{}| = {}", a, a.length(), c, c.length());
+    println!("distance from {} to {} = {}", c, Point::new(3.0_f32, 4.0), c.distance(Point::new(3.0, 4.0)));
+
+    // 类型转换：整数点 -> 浮点点总是成功
+    let exact: Point<f64> = Point::new(i32::MAX, i32::MIN).into();
+    println!("as f64: {}", exact);
+    // let lossy: Point<f32> = Point::new(16_777_217_i32, 0).into(); // 错误！i32 -> f32 会丢失精度，没有实现 From
+
+    // 浮点点 -> 整数点可能失败
+    for point in [Point::new(2.0_f64, -5.0), Point::new(0.25_f64, 0.0), Point::new(0.0, 1e10_f64)] {
+        match Point::<i32>::try_from(point) {
+            Ok(p) => println!("{} converted to {}", point, p),
+            Err(e) => println!("cannot convert {}: {}", point, e),
+        }
+    }
+}
+
+// 课程注册，见 lesson.rs
+pub struct PointLesson;
+
+impl crate::lesson::Lesson for PointLesson {
+    fn title(&self) -> &'static str {
+        "Operator Overloading"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("point.rs")
+    }
+
+    fn run(&self) {
+        point_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_operators() {
+        let p = Point::new(3, 4);
+        let q = Point::new(1, -2);
+        assert_eq!(*p.x(), 3);
+        assert_eq!(p + q, Point::new(4, 2));
+        assert_eq!(p - q, Point::new(2, 6));
+        assert_eq!(p * 3, Point::new(9, 12));
+        assert_eq!(3 * p, Point::new(9, 12));
+        assert_eq!(-p, Point::new(-3, -4));
+        assert_eq!(p.dot(q), 3 - 8);
+        assert_eq!(p + Point::default(), p);
+    }
+
+    #[test]
+    fn f64_operations() {
+        let a = Point::new(3.0_f64, 4.0);
+        let b = Point::new(0.0_f64, 0.0);
+        assert_eq!(a.length(), 5.0);
+        assert_eq!(a.distance(b), 5.0);
+        assert_eq!(a.distance_from_origin(), 5.0);
+        assert_eq!(0.5 * a, Point::new(1.5, 2.0));
+        assert_eq!(-a + a, b);
+    }
+
+    #[test]
+    fn f32_operations() {
+        let c = Point::new(6.0_f32, 8.0);
+        let d = Point::new(3.0_f32, 4.0);
+        assert_eq!(c.distance_from_origin(), 10.0);
+        assert_eq!(c.distance(d), 5.0);
+        assert_eq!(c - d, d);
+        assert_eq!(d * 2.0, c);
+        assert_eq!(2.0 * d, Point::new(6.0, 8.0));
+        assert_eq!(c.dot(d), 50.0);
+    }
+
+    #[test]
+    fn integer_to_float_is_lossless() {
+        let exact: Point<f64> = Point::new(i32::MAX, i32::MIN).into();
+        assert_eq!(exact, Point::new(2147483647.0, -2147483648.0));
+        let small: Point<f32> = Point::from(Point::new(-7_i16, 12));
+        assert_eq!(small, Point::new(-7.0, 12.0));
+        let grid: Point<f64> = Point::new(3_u8, 4_u8).into();
+        assert_eq!(grid.length(), 5.0);
+    }
+
+    #[test]
+    fn float_to_integer_can_fail() {
+        assert_eq!(Point::<i32>::try_from(Point::new(2.0_f64, -5.0)), Ok(Point::new(2, -5)));
+        assert_eq!(Point::<i32>::try_from(Point::new(1.5_f32, 0.0)), Err(PointConversionError::NotIntegral));
+        assert_eq!(Point::<i32>::try_from(Point::new(f64::NAN, 0.0)), Err(PointConversionError::NotFinite));
+        assert_eq!(Point::<i32>::try_from(Point::new(0.0, 1e10_f64)), Err(PointConversionError::OutOfRange));
+        assert_eq!(Point::<i32>::try_from(Point::new(2147483648.0_f32, 0.0)), Err(PointConversionError::OutOfRange));
+        assert_eq!(Point::<i32>::try_from(Point::new(-2147483648.0_f32, 0.0)), Ok(Point::new(i32::MIN, 0)));
+        assert_eq!(Point::<i64>::try_from(Point::new(1e10_f64, 0.0)), Ok(Point::new(10_000_000_000, 0)));
+    }
+}
@@ -0,0 +1,265 @@
+use std::cmp::Ordering;
+
+// 不会 panic 的选择函数（Selection）
+// generics.rs 中的 `largest` 有两个问题：
+// 1. `list[0]` 在空切片上会 panic；
+// 2. 它要求 `T: Copy`，所以不能用于 `String` 这样的类型。
+// 这里的函数都返回指向切片元素的引用（`Option<&T>`），空切片返回 None，任何类型都可以用。
+//
+// 浮点数只实现了 PartialOrd：NaN 和任何值比较（包括它自己）都没有结果。
+// 如果照常比较，结果会取决于 NaN 出现的位置。这里的规则是：
+// 和自己都无法比较的值（NaN）不参与比较；只有全部都是 NaN 时，才返回第一个 NaN。
+// 有多个最大值时，返回最先出现的那个。
+
+// 能和自己比较的值才是“有序”的。对整数、字符串永远成立，对浮点数排除了 NaN
+fn is_ordered<K: PartialOrd>(value: &K) -> bool {
+    value.partial_cmp(value).is_some()
+}
+
+pub fn largest<T: PartialOrd>(list: &[T]) -> Option<&T> {
+    largest_by_key(list, |item| item)
+}
+
+// 按 key 比较，返回元素本身。key 也只需要 PartialOrd，所以可以按浮点数字段比较
+pub fn largest_by_key<'a, T, K, F>(list: &'a [T], mut key: F) -> Option<&'a T>
+where
+    K: PartialOrd,
+    F: FnMut(&'a T) -> K,
+{
+    let mut best: Option<(&T, K)> = None;
+    for item in list {
+        let k = key(item);
+        if !is_ordered(&k) {
+            continue;
+        }
+        if best.as_ref().is_none_or(|(_, best_key)| k > *best_key) {
+            best = Some((item, k));
+        }
+    }
+    best.map(|(item, _)| item).or_else(|| list.first())
+}
+
+// 用比较函数决定顺序，比较函数给出的是全序，所以不需要处理 NaN
+pub fn largest_by<T, F>(list: &[T], mut compare: F) -> Option<&T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut iter = list.iter();
+    let first = iter.next()?;
+    Some(iter.fold(first, |best, item| if compare(item, best) == Ordering::Greater { item } else { best }))
+}
+
+// 最大的 k 个元素，从大到小排列，相等的元素保持原来的顺序。
+// 只维护一个长度不超过 k 的有序 Vec，时间复杂度 O(n·k)，k 很小时比整体排序更省。
+// NaN 排在所有有序值之后，所以 `top_k(list, 1)` 和 `largest(list)` 的结果总是一致的
+pub fn top_k<T: PartialOrd>(list: &[T], k: usize) -> Vec<&T> {
+    let mut top: Vec<&T> = Vec::with_capacity(k.min(list.len()));
+    let mut unordered: Vec<&T> = Vec::new();
+    if k == 0 {
+        return top;
+    }
+    for item in list {
+        if !is_ordered(item) {
+            if unordered.len() < k {
+                unordered.push(item);
+            }
+            continue;
+        }
+        // 第一个比 item 小的位置：排在所有与它相等的元素之后
+        let index = top.partition_point(|existing| *existing >= item);
+        if index < k {
+            top.insert(index, item);
+            top.truncate(k);
+        }
+    }
+    let missing = k - top.len();
+    top.extend(unordered.into_iter().take(missing));
+    top
+}
+
+// 一次遍历同时找出最小值和最大值。两者都取最先出现的那个
+pub fn min_max<T: PartialOrd>(list: &[T]) -> Option<(&T, &T)> {
+    let mut result: Option<(&T, &T)> = None;
+    for item in list.iter().filter(|item| is_ordered(*item)) {
+        result = match result {
+            None => Some((item, item)),
+            Some((min, max)) if item < min => Some((item, max)),
+            Some((min, max)) if item > max => Some((min, item)),
+            unchanged => unchanged,
+        };
+    }
+    result.or_else(|| list.first().map(|first| (first, first)))
+}
+
+#[derive(Debug, PartialEq)]
+struct City {
+    name: String,
+    population: u32,
+    area_km2: f64,
+}
+
+fn city(name: &str, population: u32, area_km2: f64) -> City {
+    City { name: name.to_string(), population, area_km2 }
+}
+
+// 亚特兰蒂斯的面积是 NaN，按密度比较时它不参与
+fn sample_cities() -> Vec<City> {
+    vec![
+        city("Shanghai", 24_870_000, 6_340.5),
+        city("Chongqing", 32_050_000, 82_402.0),
+        city("Atlantis", 0, f64::NAN),
+        city("Beijing", 21_890_000, 16_410.5),
+    ]
+}
+
+pub fn selection_example() {
+    // 和 generics.rs 一样的输入
+    let number_list = vec![34, 50, 25, 100, 65];
+    println!("largest number: {:?}, min_max: {:?}", largest(&number_list), min_max(&number_list));
+
+    // 空切片不会 panic，而是返回 None
+    let empty: Vec<i32> = Vec::new();
+    println!("largest of empty: {:?}", largest(&empty));
+
+    // 不需要 Copy：String 也可以
+    let words = vec![String::from("borrow"), String::from("trait"), String::from("lifetime")];
+    println!("largest word: {:?}, longest word: {:?}", largest(&words), largest_by_key(&words, |w| w.len()));
+
+    // 重复的最大值：返回最先出现的那个，top_k 中相等的元素保持原来的顺序
+    let duplicates = [3, 9, 1, 9, 9, 2];
+    println!("top 4 of {:?}: {:?}", duplicates, top_k(&duplicates, 4));
+
+    // NaN 不参与比较，不管它出现在哪里
+    let floats = [f64::NAN, 2.5, -1.0, f64::NAN, 7.25, 0.0];
+    println!("largest: {:?}, min_max: {:?}, top 2: {:?}", largest(&floats), min_max(&floats), top_k(&floats, 2));
+    // 对照：generics.rs 的写法遇到第一个元素是 NaN 时，`item > largest` 永远为 false，结果就是 NaN
+    let naive = floats.iter().fold(floats[0], |best, &x| if x > best { x } else { best });
+    println!("naive largest: {}", naive);
+
+    // 按字段选择，返回整个结构体
+    let cities = sample_cities();
+    let most_populous = largest_by_key(&cities, |c| c.population).unwrap();
+    let densest = largest_by_key(&cities, |c| c.population as f64 / c.area_km2).unwrap();
+    println!("most populous: {}, densest: {}", most_populous.name, densest.name);
+}
+
+// 课程注册，见 lesson.rs
+pub struct SelectionLesson;
+
+impl crate::lesson::Lesson for SelectionLesson {
+    fn title(&self) -> &'static str {
+        "Panic-Free Selection"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("selection.rs")
+    }
+
+    fn run(&self) {
+        selection_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_results_as_generics() {
+        let number_list = vec![34, 50, 25, 100, 65];
+        assert_eq!(largest(&number_list), Some(&100));
+        assert_eq!(largest(&['y', 'm', 'a', 'q']), Some(&'y'));
+    }
+
+    // 空切片和单个元素
+    #[test]
+    fn empty_and_single() {
+        let empty: Vec<i32> = Vec::new();
+        assert_eq!(largest(&empty), None);
+        assert_eq!(largest_by_key(&empty, |n| *n), None);
+        assert_eq!(largest_by(&empty, |a, b| a.cmp(b)), None);
+        assert!(top_k(&empty, 3).is_empty());
+        assert_eq!(min_max(&empty), None);
+        assert_eq!(largest(&[7]), Some(&7));
+        assert_eq!(min_max(&[7]), Some((&7, &7)));
+        assert_eq!(top_k(&[7], 3), vec![&7]);
+    }
+
+    // 不需要 Copy：String 也可以
+    #[test]
+    fn works_without_copy() {
+        let words = vec![String::from("borrow"), String::from("trait"), String::from("lifetime")];
+        assert_eq!(largest(&words), Some(&String::from("trait")));
+        assert_eq!(largest_by_key(&words, |w| w.len()).map(String::as_str), Some("lifetime"));
+        assert_eq!(largest_by(&words, |a, b| b.cmp(a)).map(String::as_str), Some("borrow"));
+    }
+
+    // 重复的最大值：返回最先出现的那个（用指针确认是同一个元素）
+    #[test]
+    fn ties_return_the_first() {
+        let duplicates = [3, 9, 1, 9, 9, 2];
+        assert!(std::ptr::eq(largest(&duplicates).unwrap(), &duplicates[1]));
+        let (min, max) = min_max(&duplicates).unwrap();
+        assert!(std::ptr::eq(min, &duplicates[2]) && std::ptr::eq(max, &duplicates[1]));
+        assert_eq!(top_k(&duplicates, 3), vec![&9, &9, &9]);
+        assert!(std::ptr::eq(top_k(&duplicates, 4)[0], &duplicates[1]));
+        assert_eq!(top_k(&duplicates, 10), vec![&9, &9, &9, &3, &2, &1]);
+        assert!(top_k(&duplicates, 0).is_empty());
+    }
+
+    // NaN 不参与比较，不管它出现在哪里
+    #[test]
+    fn nan_is_skipped() {
+        let floats = [f64::NAN, 2.5, -1.0, f64::NAN, 7.25, 0.0];
+        assert_eq!(largest(&floats), Some(&7.25));
+        assert_eq!(min_max(&floats), Some((&-1.0, &7.25)));
+        assert_eq!(top_k(&floats, 2), vec![&7.25, &2.5]);
+        let mut reversed = floats;
+        reversed.reverse();
+        assert_eq!(largest(&reversed), Some(&7.25));
+        // 对照：generics.rs 的写法结果是 NaN
+        let naive = floats.iter().fold(floats[0], |best, &x| if x > best { x } else { best });
+        assert!(naive.is_nan());
+    }
+
+    // 只有 NaN 时才返回 NaN；有序值不够 k 个时，用 NaN 补足
+    #[test]
+    fn only_nans() {
+        let nans = [f64::NAN, f64::NAN];
+        assert!(largest(&nans).is_some_and(|n| n.is_nan()));
+        assert!(min_max(&nans).is_some_and(|(min, max)| min.is_nan() && max.is_nan()));
+        let top = top_k(&[f64::NAN, 1.0], 2);
+        assert_eq!(top[0], &1.0);
+        assert!(top[1].is_nan());
+    }
+
+    // 和标准库对照（整数没有 NaN，结果必须一致）
+    #[test]
+    fn matches_std_on_integers() {
+        let samples: [&[i32]; 4] = [&[5, -3, 8, 8, 0], &[1], &[-7, -2, -9], &[4, 4, 4]];
+        for sample in samples {
+            assert_eq!(largest(sample), sample.iter().max());
+            assert_eq!(min_max(sample), sample.iter().min().zip(sample.iter().max()));
+            let mut sorted: Vec<&i32> = sample.iter().collect();
+            sorted.sort_by(|a, b| b.cmp(a));
+            sorted.truncate(2);
+            assert_eq!(top_k(sample, 2), sorted);
+        }
+    }
+
+    // 按字段选择，返回整个结构体
+    #[test]
+    fn select_by_field() {
+        let cities = sample_cities();
+        assert_eq!(largest_by_key(&cities, |c| c.population).map(|c| c.name.as_str()), Some("Chongqing"));
+        assert_eq!(largest_by_key(&cities, |c| c.population as f64 / c.area_km2).map(|c| c.name.as_str()), Some("Shanghai"));
+        let smallest_area = largest_by(&cities, |a, b| b.area_km2.total_cmp(&a.area_km2)).unwrap();
+        assert_eq!(smallest_area.name, "Shanghai");
+        // 按名字：key 可以借用元素本身
+        assert_eq!(largest_by_key(&cities, |c| c.name.as_str()), Some(&cities[0]));
+    }
+}
@@ -0,0 +1,262 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashSet};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use crate::basics::color::Color;
+use crate::basics::geometry::Rectangle;
+use crate::basics::ip::{IpAddr, IpParseError};
+use crate::basics::message::Message;
+use crate::basics::user::User;
+
+// 标准库特征（Standard Library Traits）
+// traits.rs 里的 `Summary` 是我们自己定义的特征。日常写 Rust 时更常实现的是标准库的特征：
+// 实现了 `Display` 才能用 `{}` 打印，实现了 `Ord` 才能 `sort()`，实现了 `Hash + Eq` 才能放进 HashSet，
+// 实现了 `FromStr` 才能 `"...".parse()`。这里给仓库里已有的类型补上这些特征。
+//
+// 能用 `#[derive]` 的时候就用 derive；derive 做不到或者做法不对的时候才手写。
+// 规则很简单：derive 逐个字段地比较、哈希，按字段声明的顺序。
+// 如果这正是你想要的语义，derive 就够了；否则必须手写，而且相关的几个特征要保持一致。
+//
+// 特征的实现可以写在 crate 中的任何模块里，不一定要和类型放在一起，
+// 下面这些 impl 的类型分别定义在 geometry.rs、color.rs、user.rs 和 ip.rs 中。
+
+// Ord：按面积比较矩形
+// Rectangle 派生了 PartialEq 和 Eq，比较的是全部四个字段。如果也派生 Ord，
+// 顺序会是先比 x、再比 y……和面积毫无关系，所以必须手写。
+// 手写时要和 Eq 保持一致：`a.cmp(&b) == Equal` 当且仅当 `a == b`。
+// 只比面积的话，3x4 和 2x6 会“相等”但 `!=`，BTreeSet 会把其中一个当成重复值丢掉。
+// 所以面积相同时再依次比较宽、高和位置。
+impl Ord for Rectangle {
+    fn cmp(&self, other: &Rectangle) -> Ordering {
+        self.area()
+            .cmp(&other.area())
+            .then_with(|| (self.width, self.height, self.x, self.y).cmp(&(other.width, other.height, other.x, other.y)))
+    }
+}
+
+// 实现了 Ord 的类型，PartialOrd 总是直接委托给 cmp，避免两个实现互相矛盾
+impl PartialOrd for Rectangle {
+    fn partial_cmp(&self, other: &Rectangle) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// From：从 (i32, i32, i32) 构造颜色
+// Message::ChangeColor 携带的是三个 i32，而 Color 的通道是 u8。
+// `From` 不允许失败，所以超出范围的值截断到 0..=255。需要报错的场合应该实现 `TryFrom`。
+// 实现了 From 之后，标准库的覆盖实现会自动提供反方向的 `Into`。
+impl From<(i32, i32, i32)> for Color {
+    fn from((r, g, b): (i32, i32, i32)) -> Color {
+        let channel = |value: i32| value.clamp(0, 255) as u8;
+        Color(channel(r), channel(g), channel(b))
+    }
+}
+
+// Hash：只哈希用户名和邮箱
+// User 派生了 PartialEq 和 Eq（比较所有字段），这里其实也可以派生 Hash。
+// 手写的理由是登录记录可能很长，每次哈希都遍历一遍没有必要。
+// 唯一的规则：`a == b` 时 `hash(a) == hash(b)`。
+// 只哈希 Eq 比较的字段中的一部分是允许的（代价是更多的哈希冲突），
+// 反过来哈希了 Eq 不比较的东西就错了——相等的值会落进不同的桶里。
+impl Hash for User {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.username().hash(state);
+        self.email().hash(state);
+    }
+}
+
+// FromStr：让 `"::1".parse::<IpAddr>()` 可以工作
+// derive 只能生成结构上的实现，解析文本这种事总是要手写。
+// 实现了 FromStr，`str::parse` 和 `.map(str::parse)` 这样的写法就都能用了
+impl FromStr for IpAddr {
+    type Err = IpParseError;
+
+    fn from_str(s: &str) -> Result<IpAddr, IpParseError> {
+        IpAddr::parse(s)
+    }
+}
+
+// 接受任何能转换成 Color 的参数
+fn paint(color: impl Into<Color>) -> String {
+    color.into().to_hex()
+}
+
+pub fn std_traits_example() {
+    // Display：geometry.rs 已经手写了 Display（derive 不支持 Display，输出格式只能由我们决定），
+    // Debug 则是派生的，二者的用途不同：一个给用户看，一个给开发者看
+    let small = Rectangle::sized(3, 4);
+    println!("Display: {}, Debug: {:?}", small, small);
+
+    // Ord：按面积排序，max、min、sort、BTreeSet 都能直接用
+    let mut rectangles = [
+        Rectangle::sized(10, 10),
+        Rectangle::sized(3, 4),
+        Rectangle::new(5, 5, 2, 6),
+        Rectangle::sized(1, 1),
+        Rectangle::new(-5, 0, 3, 4),
+    ];
+    rectangles.sort();
+    let sorted: Vec<String> = rectangles.iter().map(|r| r.to_string()).collect();
+    println!("sorted by area: {:?}", sorted);
+    // 面积相同但不相等的矩形不会被 BTreeSet 当成重复值
+    let set: BTreeSet<Rectangle> = rectangles.iter().copied().collect();
+    println!("BTreeSet keeps {} rectangles, largest {:?}", set.len(), set.last().map(|r| r.to_string()));
+    // 如果派生了 Ord，比较的是第一个字段 x：派生的顺序里 3x4-5+0 会排在 1x1 前面
+
+    // From 和 Into
+    let into: Color = (300, -5, 128).into();
+    println!("(300, -5, 128) -> {:?}, paint((255, 0, 0)) = {}", into, paint((255, 0, 0)));
+    // message.rs 的 ChangeColor 现在可以直接变成一个 Color
+    if let Message::ChangeColor(r, g, b) = Message::ChangeColor(0, 200, 999) {
+        println!("ChangeColor -> {}", Color::from((r, g, b)).to_hex());
+    }
+
+    // Hash：相等的用户哈希值一定相等，所以可以放进 HashSet 去重
+    let alice = User::builder().username("alice").email("alice@example.com").build().unwrap();
+    let bob = User::builder().username("bob").email("bob@example.com").build().unwrap();
+    let users: HashSet<User> = [alice.clone(), bob, alice].into_iter().collect();
+    println!("{} distinct users", users.len());
+
+    // FromStr
+    let list: Result<Vec<IpAddr>, IpParseError> = "10.0.0.1, fe80::1, 8.8.8.8".split(", ").map(str::parse).collect();
+    let list = list.unwrap();
+    println!("parsed: {}", list.iter().map(|ip| ip.to_string()).collect::<Vec<_>>().join(" | "));
+    let error = "10.0.0.1,300.0.0.1".split(',').map(IpAddr::from_str).collect::<Result<Vec<_>, _>>();
+    println!("error: {}", error.unwrap_err());
+
+    // derive 做不到的情况
+    // #[derive(Display)] struct Label(String); // 错误！Display 不能派生，输出格式必须自己决定
+    // #[derive(PartialEq, Eq, Hash)] struct Reading(f64); // 错误！f64 没有实现 Eq 和 Hash（NaN != NaN）
+    // 这也是 color.rs 里的 Hsl 只派生了 PartialEq 的原因
+}
+
+// 课程注册，见 lesson.rs
+pub struct StdTraitsLesson;
+
+impl crate::lesson::Lesson for StdTraitsLesson {
+    fn title(&self) -> &'static str {
+        "Standard Library Traits"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("std_traits.rs")
+    }
+
+    fn run(&self) {
+        std_traits_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+
+    use super::*;
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn rectangles() -> Vec<Rectangle> {
+        vec![
+            Rectangle::sized(10, 10),
+            Rectangle::sized(3, 4),
+            Rectangle::new(5, 5, 2, 6),
+            Rectangle::sized(1, 1),
+            Rectangle::new(-5, 0, 3, 4),
+        ]
+    }
+
+    #[test]
+    fn display_and_debug() {
+        let small = Rectangle::sized(3, 4);
+        assert_eq!(small.to_string(), "3x4");
+        assert_eq!(format!("{}", Rectangle::new(10, -20, 30, 50)), "30x50+10-20");
+        assert_eq!(format!("{:?}", small), "Rectangle { x: 0, y: 0, width: 3, height: 4 }");
+    }
+
+    // 按面积排序，面积相同时再比较宽、高和位置
+    #[test]
+    fn rectangles_sort_by_area() {
+        let mut rectangles = rectangles();
+        rectangles.sort();
+        let sorted: Vec<String> = rectangles.iter().map(|r| r.to_string()).collect();
+        assert_eq!(sorted, vec!["1x1", "2x6+5+5", "3x4-5+0", "3x4", "10x10"]);
+        assert_eq!(rectangles.iter().max(), Some(&Rectangle::sized(10, 10)));
+        // 更宽但面积更小
+        assert!(Rectangle::sized(5, 1) < Rectangle::sized(3, 4));
+        // 如果派生了 Ord，比较的是第一个字段 x
+        assert!((-5, 0, 3, 4) < (0, 0, 1, 1));
+    }
+
+    // 面积相同但不相等的矩形不会被 BTreeSet 当成重复值；Ord 和 Eq 一致
+    #[test]
+    fn ord_agrees_with_eq() {
+        let rectangles = rectangles();
+        let set: BTreeSet<Rectangle> = rectangles.iter().copied().collect();
+        assert_eq!(set.len(), 5);
+        for a in &rectangles {
+            for b in &rectangles {
+                assert_eq!(a.cmp(b) == Ordering::Equal, a == b);
+                assert_eq!(a.partial_cmp(b), Some(a.cmp(b)));
+            }
+        }
+    }
+
+    // 超出范围的通道截断到 0..=255；每个类型都自动实现了 From<T> for T
+    #[test]
+    fn color_from_tuple() {
+        assert_eq!(Color::from((255, 128, 0)), Color(255, 128, 0));
+        assert_eq!(Color::from((300, -5, 128)), Color(255, 0, 128));
+        let into: Color = (0, 0, 0).into();
+        assert_eq!(into, Color::BLACK);
+        assert_eq!(paint((255, 0, 0)), Color::RED.to_hex());
+        assert_eq!(paint(Color::BLUE), "#0000ff");
+        if let Message::ChangeColor(r, g, b) = Message::ChangeColor(0, 200, 999) {
+            assert_eq!(Color::from((r, g, b)), Color(0, 200, 255));
+        }
+    }
+
+    #[test]
+    fn equal_users_hash_equally() {
+        let alice = User::builder().username("alice").email("alice@example.com").build().unwrap();
+        let bob = User::builder().username("bob").email("bob@example.com").build().unwrap();
+        assert_eq!(hash_of(&alice), hash_of(&alice.clone()));
+        let users: HashSet<User> = [alice.clone(), bob.clone(), alice.clone()].into_iter().collect();
+        assert_eq!(users.len(), 2);
+        assert!(users.contains(&bob));
+
+        // 登录记录不参与哈希：哈希值相同，但两个用户并不相等，HashSet 会分别保存
+        let mut returning = alice.clone();
+        returning.sign_in().unwrap();
+        assert_eq!(hash_of(&returning), hash_of(&alice));
+        assert_ne!(returning, alice);
+        let users: HashSet<User> = [alice.clone(), returning].into_iter().collect();
+        assert_eq!(users.len(), 2);
+
+        // 换了邮箱就是不同的哈希（几乎一定）
+        let mut moved = alice.clone();
+        moved.set_email("alice@example.org").unwrap();
+        assert_ne!(hash_of(&moved), hash_of(&alice));
+    }
+
+    #[test]
+    fn ip_from_str() {
+        let loopback: IpAddr = "::1".parse().unwrap();
+        assert!(loopback.is_loopback());
+        assert_eq!("192.168.0.1".parse::<IpAddr>(), Ok(IpAddr::V4([192, 168, 0, 1])));
+        assert_eq!("01.2.3.4".parse::<IpAddr>(), IpAddr::parse("01.2.3.4"));
+        assert!("not an address".parse::<IpAddr>().is_err());
+        let list: Result<Vec<IpAddr>, IpParseError> = "10.0.0.1, fe80::1, 8.8.8.8".split(", ").map(str::parse).collect();
+        assert_eq!(list.map(|list| list.len()), Ok(3));
+        let error = "10.0.0.1,300.0.0.1".split(',').map(IpAddr::from_str).collect::<Result<Vec<_>, _>>();
+        assert_eq!(error, Err(IpParseError::Ipv4OctetOutOfRange(String::from("300"))));
+    }
+}
@@ -93,4 +93,25 @@ pub fn threads_example() {
     }
 
     println!("Result: {}", *counter.lock().unwrap());
-}
\ No newline at end of file
+}
+
+// 课程注册，见 lesson.rs
+pub struct ThreadsLesson;
+
+impl crate::lesson::Lesson for ThreadsLesson {
+    fn title(&self) -> &'static str {
+        "Threads"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("threads.rs")
+    }
+
+    fn run(&self) {
+        threads_example()
+    }
+}
@@ -1,45 +1,96 @@
-pub fn traits_example() {
-    // 特征（Traits）
-    // 特征定义了共享行为。它们类似于其他语言中的接口（interfaces）。
-    // 你可以为任何数据类型实现特征。
+// 特征（Traits）
+// 特征定义了共享行为。它们类似于其他语言中的接口（interfaces）。
+// 你可以为任何数据类型实现特征。
+// `Summary`、`NewsArticle` 和 `Tweet` 定义在模块顶层，feed.rs 会把它们放进同一个信息流。
+
+// 定义一个特征
+pub trait Summary {
+    // 必须实现的方法
+    fn summarize_author(&self) -> String;
+
+    // 默认实现可以调用同一个特征里的其他方法，哪怕那个方法本身没有默认实现。
+    // 只实现了 `summarize_author` 的类型也能得到一个可用的 `summarize`
+    fn summarize(&self) -> String {
+        format!("(Read more from {}...)", self.summarize_author())
+    }
 
-    // 定义一个特征
-    pub trait Summary {
-        fn summarize(&self) -> String;
+    // 元数据也可以有默认实现，已有的实现不需要任何改动就能放进 feed.rs 的信息流
+    // 发布时间（Unix 时间戳，秒），没有时间的内容排在最后
+    fn published_at(&self) -> Option<u64> {
+        None
+    }
 
-        // 默认实现
-        fn summarize_default(&self) -> String {
-            String::from("(Read more...)")
-        }
+    fn kind(&self) -> &'static str {
+        "post"
     }
 
-    // 为结构体实现特征
-    pub struct NewsArticle {
-        pub headline: String,
-        pub location: String,
-        pub author: String,
-        pub content: String,
+    fn tags(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+// 为结构体实现特征
+pub struct NewsArticle {
+    pub headline: String,
+    pub location: String,
+    pub author: String,
+    pub content: String,
+}
+
+impl Summary for NewsArticle {
+    fn summarize_author(&self) -> String {
+        self.author.clone()
     }
 
-    impl Summary for NewsArticle {
-        fn summarize(&self) -> String {
-            format!("{}, by {} ({})", self.headline, self.author, self.location)
-        }
+    // 重写默认实现
+    fn summarize(&self) -> String {
+        format!("{}, by {} ({})", self.headline, self.author, self.location)
+    }
+
+    fn kind(&self) -> &'static str {
+        "news"
+    }
+}
+
+pub struct Tweet {
+    pub username: String,
+    pub content: String,
+    pub reply: bool,
+    pub retweet: bool,
+}
+
+impl Summary for Tweet {
+    fn summarize_author(&self) -> String {
+        format!("@{}", self.username)
+    }
+
+    fn summarize(&self) -> String {
+        format!("{}: {}", self.username, self.content)
     }
 
-    pub struct Tweet {
-        pub username: String,
-        pub content: String,
-        pub reply: bool,
-        pub retweet: bool,
+    fn kind(&self) -> &'static str {
+        "tweet"
     }
 
-    impl Summary for Tweet {
-        fn summarize(&self) -> String {
-            format!("{}: {}", self.username, self.content)
+    // 推文里的 #话题 就是它的标签
+    fn tags(&self) -> Vec<String> {
+        hashtags(&self.content)
+    }
+}
+
+fn hashtags(text: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for word in text.split_whitespace() {
+        let Some(tag) = word.strip_prefix('#') else { continue };
+        let tag = tag.trim_end_matches(|c: char| !c.is_alphanumeric() && c != '_');
+        if !tag.is_empty() && !tags.iter().any(|t| t == tag) {
+            tags.push(tag.to_string());
         }
     }
+    tags
+}
 
+pub fn traits_example() {
     let tweet = Tweet {
         username: String::from("horse_ebooks"),
         content: String::from(
@@ -50,7 +101,21 @@ pub fn traits_example() {
     };
 
     println!("1 new tweet: {}", tweet.summarize());
-    println!("1 new tweet (default): {}", tweet.summarize_default());
+
+    // 只实现必需方法的类型使用默认的 `summarize`，它又调用了这个类型自己的 `summarize_author`
+    struct Podcast {
+        host: String,
+    }
+
+    impl Summary for Podcast {
+        fn summarize_author(&self) -> String {
+            format!("host {}", self.host)
+        }
+    }
+
+    let podcast = Podcast { host: String::from("Ferris") };
+    println!("1 new episode (default): {}", podcast.summarize());
+    println!("tweet author: {}", tweet.summarize_author());
 
     let article = NewsArticle {
         headline: String::from("Penguins win Stanley Cup in overtime!"),
@@ -119,4 +184,67 @@ pub fn traits_example() {
 
     notify_trait_object(&tweet);
     notify_trait_object(&article);
-}
\ No newline at end of file
+}
+
+// 课程注册，见 lesson.rs
+pub struct TraitsLesson;
+
+impl crate::lesson::Lesson for TraitsLesson {
+    fn title(&self) -> &'static str {
+        "Traits"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("traits.rs")
+    }
+
+    fn run(&self) {
+        traits_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tweet(content: &str) -> Tweet {
+        Tweet { username: String::from("horse_ebooks"), content: content.to_string(), reply: false, retweet: false }
+    }
+
+    // 只实现必需方法的类型使用默认的 `summarize`，它又调用了这个类型自己的 `summarize_author`
+    #[test]
+    fn default_summarize_uses_summarize_author() {
+        struct Podcast {
+            host: String,
+        }
+
+        impl Summary for Podcast {
+            fn summarize_author(&self) -> String {
+                format!("host {}", self.host)
+            }
+        }
+
+        let podcast = Podcast { host: String::from("Ferris") };
+        assert_eq!(podcast.summarize(), "(Read more from host Ferris...)");
+        assert_eq!((podcast.published_at(), podcast.kind(), podcast.tags()), (None, "post", Vec::new()));
+    }
+
+    #[test]
+    fn overridden_methods() {
+        let tweet = tweet("of course, as you probably already know, people");
+        assert_eq!(tweet.summarize_author(), "@horse_ebooks");
+        assert_eq!(tweet.summarize(), "horse_ebooks: of course, as you probably already know, people");
+        assert_eq!(tweet.kind(), "tweet");
+    }
+
+    // 标签去掉结尾的标点，重复的只保留一个
+    #[test]
+    fn tweet_tags_are_hashtags() {
+        assert_eq!(tweet("Rust 1.75 is out! #rust #release, #rust.").tags(), vec!["rust", "release"]);
+        assert!(tweet("no tags # here").tags().is_empty());
+    }
+}
@@ -0,0 +1,376 @@
+use std::fmt;
+use std::str::FromStr;
+
+// 颜色（Color）
+// `structs.rs` 中的 `Color(i32, i32, i32)` 可以写出 `Color(-1, 300, 0)` 这样毫无意义的值。
+// 把通道类型换成 u8，取值范围 0..=255 就由类型系统保证了，不需要任何运行时检查。
+// 在此基础上实现十六进制解析/格式化（`#ff8800`）、RGB 与 HSL 的互相转换、颜色混合，
+// 以及用 24 位 ANSI 转义序列在终端里画出色块。
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+// 和 `Color` 结构完全相同的元组结构体：字段类型一样，但它们是两个不同的类型
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Point(i32, i32, i32);
+
+impl Color {
+    pub const BLACK: Color = Color(0, 0, 0);
+    pub const WHITE: Color = Color(255, 255, 255);
+    pub const RED: Color = Color(255, 0, 0);
+    pub const GREEN: Color = Color(0, 255, 0);
+    pub const BLUE: Color = Color(0, 0, 255);
+
+    pub fn red(&self) -> u8 {
+        self.0
+    }
+
+    pub fn green(&self) -> u8 {
+        self.1
+    }
+
+    pub fn blue(&self) -> u8 {
+        self.2
+    }
+
+    pub fn to_hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+
+    pub fn to_hsl(self) -> Hsl {
+        let [r, g, b] = [self.0, self.1, self.2].map(|c| c as f64 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+        let lightness = (max + min) / 2.0;
+
+        // 灰色没有色相，饱和度为 0
+        if delta == 0.0 {
+            return Hsl::new(0.0, 0.0, lightness);
+        }
+
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        Hsl::new(hue, saturation, lightness)
+    }
+
+    // 线性插值：t = 0 得到 self，t = 1 得到 other，超出 [0, 1] 的 t 会被截断
+    pub fn lerp(self, other: Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Color(mix(self.0, other.0), mix(self.1, other.1), mix(self.2, other.2))
+    }
+
+    // 多个颜色按通道求平均，空列表返回 None
+    pub fn blend(colors: &[Color]) -> Option<Color> {
+        if colors.is_empty() {
+            return None;
+        }
+        let n = colors.len() as u32;
+        let sum = colors.iter().fold([0u32; 3], |[r, g, b], c| {
+            [r + c.0 as u32, g + c.1 as u32, b + c.2 as u32]
+        });
+        // 加上 n / 2 实现四舍五入
+        let [r, g, b] = sum.map(|total| ((total + n / 2) / n) as u8);
+        Some(Color(r, g, b))
+    }
+
+    // 从 `from` 到 `to` 的 `steps` 个颜色，包含两端
+    pub fn gradient(from: Color, to: Color, steps: usize) -> Vec<Color> {
+        match steps {
+            0 => Vec::new(),
+            1 => vec![from],
+            _ => (0..steps)
+                .map(|i| from.lerp(to, i as f64 / (steps - 1) as f64))
+                .collect(),
+        }
+    }
+
+    // 在 HSL 空间里调整亮度，色相不变
+    pub fn lighten(self, amount: f64) -> Color {
+        let hsl = self.to_hsl();
+        Hsl::new(hsl.hue, hsl.saturation, hsl.lightness + amount).to_rgb()
+    }
+
+    pub fn darken(self, amount: f64) -> Color {
+        self.lighten(-amount)
+    }
+
+    // WCAG 定义的相对亮度：先把 sRGB 值还原成线性光强，再按人眼敏感度加权
+    pub fn luminance(&self) -> f64 {
+        let linear = |c: u8| {
+            let c = c as f64 / 255.0;
+            if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        };
+        0.2126 * linear(self.0) + 0.7152 * linear(self.1) + 0.0722 * linear(self.2)
+    }
+
+    // 对比度在 1（相同）到 21（黑白）之间
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    // 终端色块：背景色设为这个颜色，文字选黑或白中对比度更高的一个。
+    // `\x1b[48;2;R;G;Bm` 设置 24 位背景色，`\x1b[38;2;R;G;Bm` 设置前景色，`\x1b[0m` 恢复默认。
+    pub fn preview(&self) -> String {
+        let text = if self.contrast_ratio(&Color::BLACK) >= self.contrast_ratio(&Color::WHITE) {
+            Color::BLACK
+        } else {
+            Color::WHITE
+        };
+        format!(
+            "\x1b[48;2;{};{};{}m\x1b[38;2;{};{};{}m {} \x1b[0m",
+            self.0, self.1, self.2, text.0, text.1, text.2, self.to_hex()
+        )
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseColorError {
+    MissingHash,
+    // `#` 后面的位数既不是 3 也不是 6
+    InvalidLength(usize),
+    InvalidDigit(char),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseColorError::MissingHash => write!(f, "color must start with '#'"),
+            ParseColorError::InvalidLength(n) => write!(f, "expected 3 or 6 hex digits, got {}", n),
+            ParseColorError::InvalidDigit(c) => write!(f, "invalid hex digit: {:?}", c),
+        }
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    // 支持 `#rrggbb` 和简写 `#rgb`（每一位重复一次，`#f80` 就是 `#ff8800`），不区分大小写
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.trim().strip_prefix('#').ok_or(ParseColorError::MissingHash)?;
+        let values = digits
+            .chars()
+            .map(|c| c.to_digit(16).map(|d| d as u8).ok_or(ParseColorError::InvalidDigit(c)))
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        match values[..] {
+            [r, g, b] => Ok(Color(r * 17, g * 17, b * 17)),
+            [r1, r2, g1, g2, b1, b2] => Ok(Color(r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2)),
+            _ => Err(ParseColorError::InvalidLength(values.len())),
+        }
+    }
+}
+
+// HSL：色相（hue，0..360 度）、饱和度（saturation，0..=1）、亮度（lightness，0..=1）。
+// 调亮、调暗、旋转色相这类操作在 HSL 里只需要改一个分量。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub hue: f64,
+    pub saturation: f64,
+    pub lightness: f64,
+}
+
+impl Hsl {
+    // 色相绕圈取模，饱和度和亮度截断到 [0, 1]
+    pub fn new(hue: f64, saturation: f64, lightness: f64) -> Hsl {
+        Hsl {
+            hue: hue.rem_euclid(360.0),
+            saturation: saturation.clamp(0.0, 1.0),
+            lightness: lightness.clamp(0.0, 1.0),
+        }
+    }
+
+    pub fn to_rgb(self) -> Color {
+        let chroma = (1.0 - (2.0 * self.lightness - 1.0).abs()) * self.saturation;
+        let sector = self.hue / 60.0;
+        let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = self.lightness - chroma / 2.0;
+        let channel = |v: f64| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Color(channel(r), channel(g), channel(b))
+    }
+
+    // 色相旋转 180 度得到互补色
+    pub fn complement(&self) -> Hsl {
+        Hsl::new(self.hue + 180.0, self.saturation, self.lightness)
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Hsl {
+        color.to_hsl()
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Color {
+        hsl.to_rgb()
+    }
+}
+
+fn describe(color: Color) -> String {
+    format!("rgb({}, {}, {})", color.red(), color.green(), color.blue())
+}
+
+pub fn color_example() {
+    // 元组结构体（Tuple Structs）与新类型
+    // `Color` 和 `Point` 的字段都是三个数字，但它们是不同的类型：
+    // 接收 `Color` 的函数不能传入 `Point`，即使两者的内存布局一模一样。
+    let orange = Color(255, 136, 0);
+    let origin = Point(0, 0, 0);
+    println!("{} is {}", orange, describe(orange));
+    println!("Point: ({}, {}, {})", origin.0, origin.1, origin.2);
+    // describe(origin); // 错误！期望 `Color`，却传入了 `Point`
+    // let c: Color = Color(-1, 300, 0); // 错误！u8 的范围是 0..=255，越界的字面量无法编译
+
+    // 十六进制解析和格式化
+    println!("#f80 = {:?}", "#f80".parse::<Color>());
+    if let Err(e) = "#12345g".parse::<Color>() {
+        println!("cannot parse #12345g: {}", e);
+    }
+
+    // RGB <-> HSL
+    let hsl = orange.to_hsl();
+    println!("{} = hsl({:.0}, {:.0}%, {:.0}%)", orange, hsl.hue, hsl.saturation * 100.0, hsl.lightness * 100.0);
+    println!("complement of {} = {}", orange, hsl.complement().to_rgb());
+
+    // 混合和插值
+    println!("black + white = {}", Color::BLACK.lerp(Color::WHITE, 0.5));
+    println!("lighter {}, darker {}", orange.lighten(0.2), orange.darken(0.2));
+    println!("contrast of {} on black: {:.2}", orange, orange.contrast_ratio(&Color::BLACK));
+
+    // 终端预览（需要支持 24 位真彩色的终端）
+    let swatches: Vec<String> = [Color::RED, orange, orange.darken(0.2), orange.lighten(0.2), hsl.complement().to_rgb()]
+        .iter()
+        .map(Color::preview)
+        .collect();
+    println!("{}", swatches.join(" "));
+    let gradient: String = Color::gradient(Color::BLUE, orange, 24)
+        .iter()
+        .map(|c| format!("\x1b[48;2;{};{};{}m \x1b[0m", c.0, c.1, c.2))
+        .collect();
+    println!("{}", gradient);
+}
+
+// 课程注册，见 lesson.rs
+pub struct ColorLesson;
+
+impl crate::lesson::Lesson for ColorLesson {
+    fn title(&self) -> &'static str {
+        "Colors"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("color.rs")
+    }
+
+    fn run(&self) {
+        color_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORANGE: Color = Color(255, 136, 0);
+
+    #[test]
+    fn newtypes_compare_by_value() {
+        assert_eq!(Point(0, 0, 0), Point(0, 0, 0));
+        assert_eq!(ORANGE, Color(255, 136, 0));
+    }
+
+    #[test]
+    fn hex_parsing_and_formatting() {
+        assert_eq!("#ff8800".parse::<Color>(), Ok(ORANGE));
+        assert_eq!("#FF8800".parse::<Color>(), Ok(ORANGE));
+        assert_eq!("#f80".parse::<Color>(), Ok(ORANGE));
+        assert_eq!(ORANGE.to_hex(), "#ff8800");
+        assert_eq!(Color::BLACK.to_string(), "#000000");
+        assert_eq!("ff8800".parse::<Color>(), Err(ParseColorError::MissingHash));
+        assert_eq!("#ff880".parse::<Color>(), Err(ParseColorError::InvalidLength(5)));
+        assert_eq!("#ff88zz".parse::<Color>(), Err(ParseColorError::InvalidDigit('z')));
+        assert_eq!("#".parse::<Color>(), Err(ParseColorError::InvalidLength(0)));
+    }
+
+    #[test]
+    fn hsl_of_primary_colors() {
+        assert_eq!(Color::RED.to_hsl(), Hsl::new(0.0, 1.0, 0.5));
+        assert_eq!(Color::GREEN.to_hsl(), Hsl::new(120.0, 1.0, 0.5));
+        assert_eq!(Color::BLUE.to_hsl(), Hsl::new(240.0, 1.0, 0.5));
+        assert_eq!(Color::WHITE.to_hsl(), Hsl::new(0.0, 0.0, 1.0));
+        assert_eq!(Hsl::new(-120.0, 1.0, 0.5).to_rgb(), Color::BLUE);
+        assert_eq!(Color::from(Hsl::from(ORANGE)), ORANGE);
+        assert_eq!(Color::RED.to_hsl().complement().to_rgb(), Color(0, 255, 255));
+    }
+
+    // 8 位通道的 RGB -> HSL -> RGB 往返转换是精确的
+    #[test]
+    fn hsl_round_trip_is_exact() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let color = Color(r, g, b);
+                    assert_eq!(color.to_hsl().to_rgb(), color, "round trip of {}", color);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn blending_and_interpolation() {
+        assert_eq!(Color::BLACK.lerp(Color::WHITE, 0.5), Color(128, 128, 128));
+        assert_eq!(Color::RED.lerp(Color::BLUE, 0.0), Color::RED);
+        assert_eq!(Color::RED.lerp(Color::BLUE, 2.0), Color::BLUE);
+        assert_eq!(Color::blend(&[Color::RED, Color::GREEN, Color::BLUE]), Some(Color(85, 85, 85)));
+        assert_eq!(Color::blend(&[]), None);
+        let steps = Color::gradient(Color::BLUE, ORANGE, 5);
+        assert_eq!(steps.len(), 5);
+        assert_eq!((steps[0], steps[4]), (Color::BLUE, ORANGE));
+        assert_eq!(Color::gradient(ORANGE, Color::BLUE, 1), vec![ORANGE]);
+    }
+
+    #[test]
+    fn lighten_darken_and_contrast() {
+        assert_eq!(ORANGE.lighten(1.0), Color::WHITE);
+        assert_eq!(ORANGE.darken(1.0), Color::BLACK);
+        assert!(ORANGE.lighten(0.2).luminance() > ORANGE.luminance());
+        // 对比度：黑白是 21:1，相同颜色是 1:1
+        assert!((Color::BLACK.contrast_ratio(&Color::WHITE) - 21.0).abs() < 1e-9);
+        assert_eq!(ORANGE.contrast_ratio(&ORANGE), 1.0);
+    }
+
+    #[test]
+    fn preview_uses_24_bit_escapes() {
+        assert!(ORANGE.preview().starts_with("\x1b[48;2;255;136;0m"));
+        assert!(ORANGE.preview().ends_with("\x1b[0m"));
+    }
+}
@@ -61,4 +61,25 @@ pub fn control_flow_example() {
         println!("{}!", number);
     }
     println!("LIFTOFF!!!");
-}
\ No newline at end of file
+}
+
+// 课程注册，见 lesson.rs
+pub struct ControlFlowLesson;
+
+impl crate::lesson::Lesson for ControlFlowLesson {
+    fn title(&self) -> &'static str {
+        "Control Flow"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("control_flow.rs")
+    }
+
+    fn run(&self) {
+        control_flow_example()
+    }
+}
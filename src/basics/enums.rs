@@ -13,6 +13,7 @@ pub fn enums_example() {
     let six = IpAddrKind::V6;
 
     // 带有值的枚举
+    // 这里的字符串没有经过任何校验，ip.rs 把它们解析成了结构化的地址
     enum IpAddr {
         V4(String),
         V6(String),
@@ -22,6 +23,7 @@ pub fn enums_example() {
     let loopback = IpAddr::V6(String::from("::1"));
 
     // 不同的类型和数量的关联数据
+    // message.rs 把这个枚举提到了模块级别，并为它写了一个脚本解释器
     enum Message {
         Quit,
         Move { x: i32, y: i32 },
@@ -74,7 +76,7 @@ pub fn enums_example() {
     enum UsState {
         Alabama,
         Alaska,
-        // ... 还有很多
+        // ... 还有很多，完整的 50 个州见 vending.rs
     }
 
     enum Coin {
@@ -108,4 +110,25 @@ pub fn enums_example() {
         count += 1;
     }
     println!("Count: {}", count);
-}
\ No newline at end of file
+}
+
+// 课程注册，见 lesson.rs
+pub struct EnumsLesson;
+
+impl crate::lesson::Lesson for EnumsLesson {
+    fn title(&self) -> &'static str {
+        "Enums"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("enums.rs")
+    }
+
+    fn run(&self) {
+        enums_example()
+    }
+}
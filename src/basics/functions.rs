@@ -37,4 +37,25 @@ fn five() -> i32 {
 
 fn plus_one(x: i32) -> i32 {
     x + 1 // 这是一个表达式，所以没有分号
-}
\ No newline at end of file
+}
+
+// 课程注册，见 lesson.rs
+pub struct FunctionsLesson;
+
+impl crate::lesson::Lesson for FunctionsLesson {
+    fn title(&self) -> &'static str {
+        "Functions"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("functions.rs")
+    }
+
+    fn run(&self) {
+        functions_example()
+    }
+}
@@ -0,0 +1,395 @@
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+// 几何：带位置的矩形（Geometry）
+// `structs.rs` 中的 `Rectangle` 只有宽和高。这里给矩形加上原点 (x, y)（左上角），
+// 于是可以讨论点是否在矩形内、两个矩形是否重叠、它们的交集和外接矩形。
+// 矩形覆盖的区域是左闭右开的：[x, x + width) × [y, y + height)，
+// 所以两个只是边挨着的矩形不算重叠，宽或高为 0 的矩形不包含任何点。
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rectangle {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rectangle {
+    pub fn new(x: i32, y: i32, width: u32, height: u32) -> Rectangle {
+        Rectangle { x, y, width, height }
+    }
+
+    // 原点在 (0, 0) 的矩形，相当于 `structs.rs` 里的写法
+    pub fn sized(width: u32, height: u32) -> Rectangle {
+        Rectangle::new(0, 0, width, height)
+    }
+
+    pub fn square(size: u32) -> Rectangle {
+        Rectangle::sized(size, size)
+    }
+
+    // 边界用 i64 表示：`x + width` 可能超出 i32 的范围
+    pub fn left(&self) -> i64 {
+        self.x as i64
+    }
+
+    pub fn top(&self) -> i64 {
+        self.y as i64
+    }
+
+    pub fn right(&self) -> i64 {
+        self.x as i64 + self.width as i64
+    }
+
+    pub fn bottom(&self) -> i64 {
+        self.y as i64 + self.height as i64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    // `u32 * u32` 可能溢出 u32（在 debug 模式下会 panic），但一定放得进 u64
+    pub fn area(&self) -> u64 {
+        self.width as u64 * self.height as u64
+    }
+
+    // 需要 u32 结果时使用：溢出返回 None
+    pub fn checked_area(&self) -> Option<u32> {
+        self.width.checked_mul(self.height)
+    }
+
+    pub fn perimeter(&self) -> u64 {
+        2 * (self.width as u64 + self.height as u64)
+    }
+
+    // 只比较尺寸，不看位置。相等的矩形也能放得下。
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width >= other.width && self.height >= other.height
+    }
+
+    pub fn contains_point(&self, x: i32, y: i32) -> bool {
+        let (x, y) = (x as i64, y as i64);
+        self.left() <= x && x < self.right() && self.top() <= y && y < self.bottom()
+    }
+
+    pub fn overlaps(&self, other: &Rectangle) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    // 两个矩形的公共部分。没有公共面积（包括只是边挨着）时返回 None。
+    pub fn intersection(&self, other: &Rectangle) -> Option<Rectangle> {
+        let left = self.left().max(other.left());
+        let top = self.top().max(other.top());
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+
+        if left >= right || top >= bottom {
+            return None;
+        }
+        // 交集不会比任何一个矩形更大，所以这些转换都不会丢失数据
+        Some(Rectangle::new(
+            left as i32,
+            top as i32,
+            (right - left) as u32,
+            (bottom - top) as u32,
+        ))
+    }
+
+    // 同时包含两个矩形的最小矩形（外接矩形）。
+    // 两个矩形相距极远时宽高可能超过 u32::MAX，此时结果饱和到 u32::MAX。
+    pub fn union(&self, other: &Rectangle) -> Rectangle {
+        let left = self.left().min(other.left());
+        let top = self.top().min(other.top());
+        let right = self.right().max(other.right());
+        let bottom = self.bottom().max(other.bottom());
+
+        Rectangle::new(
+            left as i32,
+            top as i32,
+            (right - left).min(u32::MAX as i64) as u32,
+            (bottom - top).min(u32::MAX as i64) as u32,
+        )
+    }
+
+    // 一组矩形的外接矩形，空列表返回 None
+    pub fn bounding_box<'a, I>(rectangles: I) -> Option<Rectangle>
+    where
+        I: IntoIterator<Item = &'a Rectangle>,
+    {
+        rectangles
+            .into_iter()
+            .copied()
+            .reduce(|bounds, rectangle| bounds.union(&rectangle))
+    }
+}
+
+// 格式："30x50"（原点在 (0, 0) 时），或者 "30x50+10-20"（带偏移）
+impl fmt::Display for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)?;
+        if self.x != 0 || self.y != 0 {
+            write!(f, "{:+}{:+}", self.x, self.y)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRectangleError {
+    Empty,
+    MissingSeparator,
+    InvalidWidth(ParseIntError),
+    InvalidHeight(ParseIntError),
+    InvalidOffset(String),
+}
+
+impl fmt::Display for ParseRectangleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseRectangleError::Empty => write!(f, "empty rectangle string"),
+            ParseRectangleError::MissingSeparator => write!(f, "expected WIDTHxHEIGHT, e.g. 30x50"),
+            ParseRectangleError::InvalidWidth(e) => write!(f, "invalid width: {}", e),
+            ParseRectangleError::InvalidHeight(e) => write!(f, "invalid height: {}", e),
+            ParseRectangleError::InvalidOffset(s) => write!(f, "invalid offset: {:?}", s),
+        }
+    }
+}
+
+impl std::error::Error for ParseRectangleError {}
+
+impl FromStr for Rectangle {
+    type Err = ParseRectangleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseRectangleError::Empty);
+        }
+
+        let (width, rest) = s
+            .split_once(['x', 'X'])
+            .ok_or(ParseRectangleError::MissingSeparator)?;
+        let width = width.parse().map_err(ParseRectangleError::InvalidWidth)?;
+
+        // 高度后面可能跟着 "+X+Y" 形式的偏移
+        let offset_start = rest.find(['+', '-']).unwrap_or(rest.len());
+        let (height, offset) = rest.split_at(offset_start);
+        let height = height.parse().map_err(ParseRectangleError::InvalidHeight)?;
+
+        let (x, y) = if offset.is_empty() {
+            (0, 0)
+        } else {
+            let invalid = || ParseRectangleError::InvalidOffset(offset.to_string());
+            let second = offset[1..].find(['+', '-']).ok_or_else(invalid)? + 1;
+            let (x, y) = offset.split_at(second);
+            (x.parse().map_err(|_| invalid())?, y.parse().map_err(|_| invalid())?)
+        };
+
+        Ok(Rectangle::new(x, y, width, height))
+    }
+}
+
+pub fn geometry_example() {
+    // 几何：带位置的矩形
+    // 和 structs.rs 一样从尺寸出发，但现在矩形有了位置。
+    let rect1 = Rectangle::sized(30, 50);
+    let rect2 = Rectangle::new(10, 20, 40, 40);
+    println!("rect1 = {}, rect2 = {}", rect1, rect2);
+    println!("rect1: area = {}, perimeter = {}", rect1.area(), rect1.perimeter());
+
+    // can_hold 只比较尺寸，而且不再是严格比较：相等的矩形也放得下
+    println!("rect1 can hold itself: {}", rect1.can_hold(&rect1));
+
+    // 点是否在矩形内：左闭右开
+    println!("rect1 contains (29, 49): {}, (30, 49): {}", rect1.contains_point(29, 49), rect1.contains_point(30, 49));
+
+    // 交集、重叠和外接矩形
+    let overlap = rect1.intersection(&rect2);
+    println!("rect1 ∩ rect2 = {:?}", overlap.map(|r| r.to_string()));
+    println!("rect1 ∪ rect2 = {}", rect1.union(&rect2));
+
+    // 只是边挨着的矩形没有公共面积
+    let neighbour = Rectangle::new(30, 0, 10, 10);
+    println!("rect1 overlaps {}: {}", neighbour, rect1.overlaps(&neighbour));
+
+    let bounds = Rectangle::bounding_box(&[rect1, rect2, neighbour]);
+    println!("bounding box = {:?}", bounds.map(|r| r.to_string()));
+
+    // 边界情况：宽或高为 0 的矩形
+    let line = Rectangle::new(5, 5, 0, 10);
+    println!("{}: empty = {}, area = {}, contains (5, 5): {}", line, line.is_empty(), line.area(), line.contains_point(5, 5));
+
+    // 边界情况：面积超出 u32
+    // structs.rs 里的 `self.width * self.height` 在这里会溢出（debug 模式下 panic）。
+    let huge = Rectangle::sized(u32::MAX, u32::MAX);
+    println!("{}: area = {}, checked_area = {:?}", huge, huge.area(), huge.checked_area());
+
+    // 解析和打印 "30x50" 格式
+    let parsed: Rectangle = "40X40+10+20".parse().unwrap();
+    println!("parsed {:?}", parsed);
+    match "30x".parse::<Rectangle>() {
+        Ok(r) => println!("parsed {}", r),
+        Err(e) => println!("\"30x\" is not a rectangle: {}", e),
+    }
+}
+
+// 课程注册，见 lesson.rs
+pub struct GeometryLesson;
+
+impl crate::lesson::Lesson for GeometryLesson {
+    fn title(&self) -> &'static str {
+        "Geometry"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("geometry.rs")
+    }
+
+    fn run(&self) {
+        geometry_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measurements() {
+        let rect1 = Rectangle::sized(30, 50);
+        assert_eq!((rect1.area(), rect1.perimeter()), (1500, 160));
+        assert_eq!(Rectangle::square(3).perimeter(), 12);
+    }
+
+    #[test]
+    fn can_hold_is_not_strict() {
+        let rect1 = Rectangle::sized(30, 50);
+        assert!(rect1.can_hold(&Rectangle::sized(10, 40)));
+        assert!(rect1.can_hold(&rect1));
+        assert!(!rect1.can_hold(&Rectangle::sized(60, 45)));
+    }
+
+    #[test]
+    fn contains_point_is_half_open() {
+        let rect1 = Rectangle::sized(30, 50);
+        assert!(rect1.contains_point(0, 0));
+        assert!(rect1.contains_point(29, 49));
+        assert!(!rect1.contains_point(30, 49));
+        assert!(!rect1.contains_point(-1, 0));
+    }
+
+    #[test]
+    fn intersection_union_and_bounding_box() {
+        let rect1 = Rectangle::sized(30, 50);
+        let rect2 = Rectangle::new(10, 20, 40, 40);
+        assert_eq!(rect1.intersection(&rect2), Some(Rectangle::new(10, 20, 20, 30)));
+        assert!(rect1.overlaps(&rect2));
+        assert_eq!(rect1.union(&rect2), Rectangle::new(0, 0, 50, 60));
+
+        // 只是边挨着的矩形没有公共面积
+        let neighbour = Rectangle::new(30, 0, 10, 10);
+        assert!(!rect1.overlaps(&neighbour));
+        assert_eq!(rect1.intersection(&neighbour), None);
+
+        assert_eq!(Rectangle::bounding_box(&[rect1, rect2, neighbour]), Some(Rectangle::new(0, 0, 50, 60)));
+        assert_eq!(Rectangle::bounding_box(&[]), None);
+    }
+
+    #[test]
+    fn zero_size_rectangles() {
+        let rect1 = Rectangle::sized(30, 50);
+        let line = Rectangle::new(5, 5, 0, 10);
+        assert!(line.is_empty());
+        assert_eq!(line.area(), 0);
+        assert_eq!(line.perimeter(), 20);
+        assert!(!line.contains_point(5, 5));
+        assert!(!line.overlaps(&rect1));
+        assert!(rect1.can_hold(&line));
+    }
+
+    #[test]
+    fn area_beyond_u32() {
+        let huge = Rectangle::sized(u32::MAX, u32::MAX);
+        assert_eq!(huge.area(), u32::MAX as u64 * u32::MAX as u64);
+        assert_eq!(huge.checked_area(), None);
+        assert_eq!(Rectangle::sized(65_536, 65_535).checked_area(), Some(4_294_901_760));
+        assert_eq!(Rectangle::sized(65_536, 65_536).checked_area(), None);
+    }
+
+    #[test]
+    fn edges_beyond_i32() {
+        let far = Rectangle::new(i32::MAX, i32::MAX, u32::MAX, 1);
+        assert!(far.contains_point(i32::MAX, i32::MAX));
+        assert_eq!(far.right(), i32::MAX as i64 + u32::MAX as i64);
+        let spread = Rectangle::new(i32::MIN, 0, 1, 1).union(&far);
+        assert_eq!(spread.width, u32::MAX);
+    }
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!("30x50".parse::<Rectangle>(), Ok(Rectangle::sized(30, 50)));
+        assert_eq!("40X40+10+20".parse::<Rectangle>(), Ok(Rectangle::new(10, 20, 40, 40)));
+        assert_eq!("5x5-3+7".parse::<Rectangle>(), Ok(Rectangle::new(-3, 7, 5, 5)));
+        assert_eq!(Rectangle::new(-3, 7, 5, 5).to_string(), "5x5-3+7");
+        assert_eq!("".parse::<Rectangle>(), Err(ParseRectangleError::Empty));
+        assert_eq!("3050".parse::<Rectangle>(), Err(ParseRectangleError::MissingSeparator));
+        assert!(matches!("-3x5".parse::<Rectangle>(), Err(ParseRectangleError::InvalidWidth(_))));
+        assert!(matches!("3x".parse::<Rectangle>(), Err(ParseRectangleError::InvalidHeight(_))));
+        assert!(matches!("3x5+1".parse::<Rectangle>(), Err(ParseRectangleError::InvalidOffset(_))));
+    }
+
+    // 性质测试（property-style）：对一组矩形两两检查几何规律
+    fn samples() -> Vec<Rectangle> {
+        [-3, 0, 4]
+            .iter()
+            .flat_map(|&x| [0, 2].map(move |y| (x, y)))
+            .flat_map(|(x, y)| [0, 1, 5].map(move |w| (x, y, w)))
+            .flat_map(|(x, y, w)| [0, 3, 6].map(move |h| Rectangle::new(x, y, w, h)))
+            .collect()
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for a in samples() {
+            assert_eq!(a.to_string().parse::<Rectangle>(), Ok(a));
+            assert_eq!(a.checked_area().map(u64::from), Some(a.area()));
+        }
+    }
+
+    #[test]
+    fn intersection_and_union_properties() {
+        let samples = samples();
+        for a in &samples {
+            for b in &samples {
+                let both = a.intersection(b);
+                // 交集满足交换律，并且和 overlaps 一致
+                assert_eq!(both, b.intersection(a));
+                assert_eq!(both.is_some(), a.overlaps(b));
+                // 外接矩形满足交换律，并且能放下两个矩形
+                let bounds = a.union(b);
+                assert_eq!(bounds, b.union(a));
+                assert!(bounds.can_hold(a) && bounds.can_hold(b));
+
+                for px in -4..12 {
+                    for py in -1..10 {
+                        let in_a = a.contains_point(px, py);
+                        let in_b = b.contains_point(px, py);
+                        // 点在交集中 <=> 点同时在两个矩形中
+                        assert_eq!(both.is_some_and(|r| r.contains_point(px, py)), in_a && in_b);
+                        // 在任意一个矩形中的点一定在外接矩形中
+                        if in_a || in_b {
+                            assert!(bounds.contains_point(px, py));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
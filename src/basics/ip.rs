@@ -0,0 +1,461 @@
+use std::fmt;
+use std::net;
+
+// IP 地址解析（IP Addresses）
+// `enums.rs` 中的 `IpAddr::V4(String)` 什么字符串都能装，`"999.1"` 也是一个“IPv4 地址”。
+// 这里让每个变体携带结构化的数据：IPv4 是四个字节，IPv6 是八个 16 位的段。
+// 只能通过解析得到这些值，于是一个 `IpAddr` 一定是合法的地址。
+//
+// 标准库的设计不太一样：`std::net::IpAddr` 是一个包着 `Ipv4Addr` 和 `Ipv6Addr` 两个结构体的枚举，
+// 每个结构体都有自己的方法，`IpAddr` 上的方法再分发给它们。
+// 我们直接把数组放在变体里，所有逻辑都写在枚举的 `match` 中。
+// 单元测试会用 `std::net` 逐一核对解析、格式化和分类的结果。
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IpAddr {
+    V4([u8; 4]),
+    V6([u16; 8]),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IpParseError {
+    Empty,
+    // IPv4 必须恰好有 4 段
+    Ipv4OctetCount(usize),
+    // 空的段或者含有非数字字符
+    Ipv4InvalidOctet(String),
+    Ipv4OctetOutOfRange(String),
+    // `01` 这样的写法有歧义（有些实现把它当作八进制），所以不允许
+    Ipv4LeadingZero(String),
+    // 没有 `::` 时必须恰好 8 段，有 `::` 时最多 7 段
+    Ipv6SegmentCount(usize),
+    // 空的段、非十六进制字符或者超过 4 位
+    Ipv6InvalidSegment(String),
+    Ipv6MultipleCompression,
+    // 内嵌的 IPv4 地址只能出现在最后
+    Ipv6MisplacedIpv4,
+}
+
+impl fmt::Display for IpParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IpParseError::Empty => write!(f, "empty address"),
+            IpParseError::Ipv4OctetCount(n) => write!(f, "IPv4 address needs 4 octets, found {}", n),
+            IpParseError::Ipv4InvalidOctet(s) => write!(f, "invalid IPv4 octet {:?}", s),
+            IpParseError::Ipv4OctetOutOfRange(s) => write!(f, "IPv4 octet {} is greater than 255", s),
+            IpParseError::Ipv4LeadingZero(s) => write!(f, "IPv4 octet {:?} has a leading zero", s),
+            IpParseError::Ipv6SegmentCount(n) => write!(f, "wrong number of IPv6 segments: {}", n),
+            IpParseError::Ipv6InvalidSegment(s) => write!(f, "invalid IPv6 segment {:?}", s),
+            IpParseError::Ipv6MultipleCompression => write!(f, "`::` may appear only once"),
+            IpParseError::Ipv6MisplacedIpv4 => write!(f, "embedded IPv4 address must be at the end"),
+        }
+    }
+}
+
+impl std::error::Error for IpParseError {}
+
+pub fn parse_v4(s: &str) -> Result<[u8; 4], IpParseError> {
+    let parts: Vec<&str> = s.split('.').collect();
+    if parts.len() != 4 {
+        return Err(IpParseError::Ipv4OctetCount(parts.len()));
+    }
+
+    let mut octets = [0u8; 4];
+    for (octet, part) in octets.iter_mut().zip(&parts) {
+        if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(IpParseError::Ipv4InvalidOctet(part.to_string()));
+        }
+        if part.len() > 1 && part.starts_with('0') {
+            return Err(IpParseError::Ipv4LeadingZero(part.to_string()));
+        }
+        // 全是数字时只可能因为数值太大而失败
+        *octet = part
+            .parse()
+            .map_err(|_| IpParseError::Ipv4OctetOutOfRange(part.to_string()))?;
+    }
+    Ok(octets)
+}
+
+// 一组用 `:` 分隔的段，最后一段可以是内嵌的 IPv4 地址（占两个段）
+fn parse_segments(s: &str, allow_ipv4: bool) -> Result<Vec<u16>, IpParseError> {
+    if s.is_empty() {
+        return Ok(Vec::new());
+    }
+    let parts: Vec<&str> = s.split(':').collect();
+    let mut segments = Vec::new();
+
+    for (index, part) in parts.iter().enumerate() {
+        if part.contains('.') {
+            if !allow_ipv4 || index + 1 != parts.len() {
+                return Err(IpParseError::Ipv6MisplacedIpv4);
+            }
+            let [a, b, c, d] = parse_v4(part)?;
+            segments.push(u16::from_be_bytes([a, b]));
+            segments.push(u16::from_be_bytes([c, d]));
+        } else if part.is_empty() || part.len() > 4 || !part.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(IpParseError::Ipv6InvalidSegment(part.to_string()));
+        } else {
+            segments.push(u16::from_str_radix(part, 16).unwrap());
+        }
+    }
+    Ok(segments)
+}
+
+pub fn parse_v6(s: &str) -> Result<[u16; 8], IpParseError> {
+    let mut segments = [0u16; 8];
+
+    match s.split_once("::") {
+        // 没有压缩：必须正好 8 段
+        None => {
+            let parsed = parse_segments(s, true)?;
+            if parsed.len() != 8 {
+                return Err(IpParseError::Ipv6SegmentCount(parsed.len()));
+            }
+            segments.copy_from_slice(&parsed);
+        }
+        // `::` 代表至少一个全零的段，左右两边加起来最多 7 段
+        Some((head, tail)) => {
+            if tail.contains("::") {
+                return Err(IpParseError::Ipv6MultipleCompression);
+            }
+            let head = parse_segments(head, false)?;
+            let tail = parse_segments(tail, true)?;
+            let count = head.len() + tail.len();
+            if count > 7 {
+                return Err(IpParseError::Ipv6SegmentCount(count));
+            }
+            segments[..head.len()].copy_from_slice(&head);
+            segments[8 - tail.len()..].copy_from_slice(&tail);
+        }
+    }
+    Ok(segments)
+}
+
+impl IpAddr {
+    // 含有 `:` 的一定是 IPv6，否则按 IPv4 解析。
+    // 标准库的地址类型通过 `FromStr` 支持 `"::1".parse()`，这里先用一个普通的关联函数，
+    // std_traits.rs 在它的基础上实现了 `FromStr`
+    pub fn parse(s: &str) -> Result<IpAddr, IpParseError> {
+        if s.is_empty() {
+            Err(IpParseError::Empty)
+        } else if s.contains(':') {
+            parse_v6(s).map(IpAddr::V6)
+        } else {
+            parse_v4(s).map(IpAddr::V4)
+        }
+    }
+
+    pub fn is_loopback(&self) -> bool {
+        match self {
+            IpAddr::V4([a, ..]) => *a == 127,
+            IpAddr::V6(segments) => *segments == [0, 0, 0, 0, 0, 0, 0, 1],
+        }
+    }
+
+    pub fn is_unspecified(&self) -> bool {
+        match self {
+            IpAddr::V4(octets) => *octets == [0; 4],
+            IpAddr::V6(segments) => *segments == [0; 8],
+        }
+    }
+
+    // 私有地址：IPv4 的 10.0.0.0/8、172.16.0.0/12、192.168.0.0/16（RFC 1918），
+    // IPv6 的唯一本地地址 fc00::/7（RFC 4193）
+    pub fn is_private(&self) -> bool {
+        match self {
+            IpAddr::V4([10, ..]) => true,
+            IpAddr::V4([172, b, ..]) => (16..=31).contains(b),
+            IpAddr::V4([192, 168, ..]) => true,
+            IpAddr::V4(_) => false,
+            IpAddr::V6([first, ..]) => first & 0xfe00 == 0xfc00,
+        }
+    }
+
+    // 组播：IPv4 的 224.0.0.0/4，IPv6 的 ff00::/8
+    pub fn is_multicast(&self) -> bool {
+        match self {
+            IpAddr::V4([a, ..]) => (224..=239).contains(a),
+            IpAddr::V6([first, ..]) => first >> 8 == 0xff,
+        }
+    }
+
+    // IPv4 映射地址 ::ffff:a.b.c.d 中的 IPv4 地址
+    pub fn to_ipv4_mapped(self) -> Option<[u8; 4]> {
+        match self {
+            IpAddr::V6([0, 0, 0, 0, 0, 0xffff, high, low]) => {
+                let [a, b] = high.to_be_bytes();
+                let [c, d] = low.to_be_bytes();
+                Some([a, b, c, d])
+            }
+            _ => None,
+        }
+    }
+}
+
+// 最长的一段连续的全零段：(起点, 长度)。长度相同时取最前面的一段
+fn longest_zero_run(segments: &[u16; 8]) -> Option<(usize, usize)> {
+    let mut best: Option<(usize, usize)> = None;
+    let mut start = 0;
+    while start < segments.len() {
+        let len = segments[start..].iter().take_while(|&&s| s == 0).count();
+        if len > best.map_or(0, |(_, best_len)| best_len) {
+            best = Some((start, len));
+        }
+        start += len.max(1);
+    }
+    best
+}
+
+fn write_segments(f: &mut fmt::Formatter, segments: &[u16]) -> fmt::Result {
+    for (i, segment) in segments.iter().enumerate() {
+        if i > 0 {
+            write!(f, ":")?;
+        }
+        write!(f, "{:x}", segment)?;
+    }
+    Ok(())
+}
+
+// 规范格式（RFC 5952）：
+// - 十六进制字母小写，去掉每段的前导零
+// - 最长的一串（至少两个）全零段压缩成 `::`，一样长时压缩第一串
+// - IPv4 映射地址写成 `::ffff:192.0.2.1`
+impl fmt::Display for IpAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IpAddr::V4([a, b, c, d]) => write!(f, "{}.{}.{}.{}", a, b, c, d),
+            IpAddr::V6(_) if self.to_ipv4_mapped().is_some() => {
+                let [a, b, c, d] = self.to_ipv4_mapped().unwrap();
+                write!(f, "::ffff:{}.{}.{}.{}", a, b, c, d)
+            }
+            IpAddr::V6(segments) => match longest_zero_run(segments) {
+                Some((start, len)) if len >= 2 => {
+                    write_segments(f, &segments[..start])?;
+                    write!(f, "::")?;
+                    write_segments(f, &segments[start + len..])
+                }
+                _ => write_segments(f, segments),
+            },
+        }
+    }
+}
+
+// 和标准库互相转换，方便核对结果
+impl From<IpAddr> for net::IpAddr {
+    fn from(addr: IpAddr) -> net::IpAddr {
+        match addr {
+            IpAddr::V4(octets) => net::IpAddr::V4(net::Ipv4Addr::from(octets)),
+            IpAddr::V6(segments) => net::IpAddr::V6(net::Ipv6Addr::from(segments)),
+        }
+    }
+}
+
+impl From<net::IpAddr> for IpAddr {
+    fn from(addr: net::IpAddr) -> IpAddr {
+        match addr {
+            net::IpAddr::V4(v4) => IpAddr::V4(v4.octets()),
+            net::IpAddr::V6(v6) => IpAddr::V6(v6.segments()),
+        }
+    }
+}
+
+pub fn ip_example() {
+    // 解析成结构化的数据
+    let home = IpAddr::parse("127.0.0.1").unwrap();
+    let loopback = IpAddr::parse("::1").unwrap();
+    println!("home = {:?}, loopback = {:?}", home, loopback);
+
+    // `::` 可以出现在开头、中间或结尾，代表一串全零的段
+    for input in ["2001:db8::8a2e:370:7334", "fe80::", "::", "::ffff:192.0.2.1"] {
+        println!("{:?} -> {:x?}", input, IpAddr::parse(input).unwrap());
+    }
+
+    // 精确的错误
+    for input in ["", "1.2.3", "1.2..4", "256.0.0.1", "10.01.0.1", "1:2:3:4:5:6:7", "1::2::3", "12345::", "1.2.3.4::"] {
+        println!("{:?}: {}", input, IpAddr::parse(input).unwrap_err());
+    }
+
+    // 规范格式
+    for input in ["2001:0DB8:0000:0000:0000:0000:0000:0001", "1:0:0:2:0:0:0:3", "::ffff:c000:0201"] {
+        println!("{} -> {}", input, IpAddr::parse(input).unwrap());
+    }
+
+    // 地址分类
+    for input in ["127.0.0.1", "192.168.1.10", "172.32.0.1", "ff02::1", "fd12:3456::1", "0.0.0.0"] {
+        let addr = IpAddr::parse(input).unwrap();
+        println!(
+            "{} loopback={} private={} multicast={} unspecified={}",
+            addr,
+            addr.is_loopback(),
+            addr.is_private(),
+            addr.is_multicast(),
+            addr.is_unspecified()
+        );
+    }
+    println!("mapped: {:?}", IpAddr::parse("::ffff:10.0.0.1").unwrap().to_ipv4_mapped());
+
+    // 和标准库对比：两边的格式化结果一样
+    let ours = IpAddr::parse("2001:db8:0:0:1:0:0:1").unwrap();
+    let theirs = net::IpAddr::from(ours);
+    println!("ours = {}, std = {}", ours, theirs);
+}
+
+// 课程注册，见 lesson.rs
+pub struct IpLesson;
+
+impl crate::lesson::Lesson for IpLesson {
+    fn title(&self) -> &'static str {
+        "IP Addresses"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("ip.rs")
+    }
+
+    fn run(&self) {
+        ip_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 用标准库核对一个字符串：能否解析、解析结果、格式化结果和分类都必须一致
+    fn check_against_std(s: &str) {
+        let ours = IpAddr::parse(s);
+        let theirs = s.parse::<net::IpAddr>();
+        assert_eq!(ours.is_ok(), theirs.is_ok(), "{:?}: {:?} vs {:?}", s, ours, theirs);
+
+        if let (Ok(ours), Ok(theirs)) = (ours, theirs) {
+            assert_eq!(net::IpAddr::from(ours), theirs, "{:?}", s);
+            assert_eq!(ours.to_string(), theirs.to_string(), "{:?}", s);
+            assert_eq!(ours.is_loopback(), theirs.is_loopback(), "{:?}", s);
+            assert_eq!(ours.is_unspecified(), theirs.is_unspecified(), "{:?}", s);
+            assert_eq!(ours.is_multicast(), theirs.is_multicast(), "{:?}", s);
+            if let net::IpAddr::V4(v4) = theirs {
+                assert_eq!(ours.is_private(), v4.is_private(), "{:?}", s);
+            }
+        }
+    }
+
+    // xorshift 伪随机数，固定种子，每次运行结果都一样
+    fn xorshift(mut seed: u64) -> impl FnMut() -> u64 {
+        move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        }
+    }
+
+    #[test]
+    fn parses_structured_addresses() {
+        assert_eq!(IpAddr::parse("127.0.0.1"), Ok(IpAddr::V4([127, 0, 0, 1])));
+        assert_eq!(IpAddr::parse("::1"), Ok(IpAddr::V6([0, 0, 0, 0, 0, 0, 0, 1])));
+        assert_eq!(IpAddr::parse("2001:db8::8a2e:370:7334"), Ok(IpAddr::V6([0x2001, 0xdb8, 0, 0, 0, 0x8a2e, 0x370, 0x7334])));
+        assert_eq!(IpAddr::parse("fe80::"), Ok(IpAddr::V6([0xfe80, 0, 0, 0, 0, 0, 0, 0])));
+        assert_eq!(IpAddr::parse("::"), Ok(IpAddr::V6([0; 8])));
+        assert_eq!(IpAddr::parse("::ffff:192.0.2.1"), Ok(IpAddr::V6([0, 0, 0, 0, 0, 0xffff, 0xc000, 0x0201])));
+    }
+
+    #[test]
+    fn precise_errors() {
+        let errors = [
+            ("", IpParseError::Empty),
+            ("1.2.3", IpParseError::Ipv4OctetCount(3)),
+            ("1.2.3.4.5", IpParseError::Ipv4OctetCount(5)),
+            ("1.2..4", IpParseError::Ipv4InvalidOctet(String::new())),
+            ("1.2.3.x", IpParseError::Ipv4InvalidOctet(String::from("x"))),
+            ("1.2.3.-4", IpParseError::Ipv4InvalidOctet(String::from("-4"))),
+            ("256.0.0.1", IpParseError::Ipv4OctetOutOfRange(String::from("256"))),
+            ("10.01.0.1", IpParseError::Ipv4LeadingZero(String::from("01"))),
+            ("1:2:3:4:5:6:7", IpParseError::Ipv6SegmentCount(7)),
+            ("1:2:3:4::5:6:7:8", IpParseError::Ipv6SegmentCount(8)),
+            ("1::2::3", IpParseError::Ipv6MultipleCompression),
+            ("12345::", IpParseError::Ipv6InvalidSegment(String::from("12345"))),
+            ("g::", IpParseError::Ipv6InvalidSegment(String::from("g"))),
+            (":1::", IpParseError::Ipv6InvalidSegment(String::new())),
+            ("1.2.3.4::", IpParseError::Ipv6MisplacedIpv4),
+            ("::1.2.3.4:5", IpParseError::Ipv6MisplacedIpv4),
+            ("::ffff:1.2.3.256", IpParseError::Ipv4OctetOutOfRange(String::from("256"))),
+        ];
+        for (input, expected) in errors {
+            assert_eq!(IpAddr::parse(input), Err(expected), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn canonical_formatting() {
+        let canonical = [
+            ("2001:0DB8:0000:0000:0000:0000:0000:0001", "2001:db8::1"),
+            ("2001:db8:0:0:1:0:0:1", "2001:db8::1:0:0:1"),
+            ("2001:db8:0:1:1:1:1:1", "2001:db8:0:1:1:1:1:1"),
+            ("0:0:0:0:0:0:0:0", "::"),
+            ("1:0:0:2:0:0:0:3", "1:0:0:2::3"),
+            ("::ffff:c000:0201", "::ffff:192.0.2.1"),
+            ("::192.0.2.1", "::c000:201"),
+        ];
+        for (input, expected) in canonical {
+            assert_eq!(IpAddr::parse(input).unwrap().to_string(), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn classification() {
+        let parse = |s| IpAddr::parse(s).unwrap();
+        assert!(parse("127.0.0.1").is_loopback() && parse("::1").is_loopback());
+        assert!(parse("192.168.1.10").is_private() && !parse("192.168.1.10").is_loopback());
+        assert!(!parse("172.32.0.1").is_private());
+        assert!(parse("ff02::1").is_multicast() && !parse("ff02::1").is_private());
+        assert!(parse("fd12:3456::1").is_private());
+        assert!(parse("0.0.0.0").is_unspecified());
+        assert_eq!(parse("::ffff:10.0.0.1").to_ipv4_mapped(), Some([10, 0, 0, 1]));
+        assert_eq!(parse("::1").to_ipv4_mapped(), None);
+    }
+
+    // 和标准库核对：手写的例子
+    #[test]
+    fn matches_std_on_samples() {
+        let samples = [
+            "0.0.0.0", "255.255.255.255", "10.0.0.1", "172.16.5.4", "172.31.255.255", "172.32.0.0",
+            "192.168.0.1", "224.0.0.251", "239.255.255.255", "127.255.0.1", "8.8.8.8", "1.2.3.04",
+            "::", "::1", "1::", "ff02::fb", "fc00::1", "fe80::1ff:fe23:4567:890a", "::ffff:0.0.0.0",
+            "1:2:3:4:5:6:7:8", "1:2:3:4:5:6:1.2.3.4", "1::1.2.3.4", "::1:2:3:4:5:6:7", "1:2:3:4:5:6:7::",
+            "0:0:1:0:0:1:0:0", "a:b:c:d:e:f:0:0", "2001:db8::", ":::", "1:::2", "1:2:3:4:5:6:7:8:9",
+            "::00001", "::0001", "1.2.3.4.", ".1.2.3.4", "1..2.3", "  1.2.3.4", "::ffff:1.2.3", "ABCD::EF01",
+        ];
+        for s in samples {
+            check_against_std(s);
+        }
+    }
+
+    // 和标准库核对：随机生成的字符串，偏向容易出错的字符
+    #[test]
+    fn matches_std_on_random_strings() {
+        let mut next = xorshift(0x2545_f491_4f6c_dd1d);
+        let alphabet: Vec<char> = "0123456789abcdefABCDEF::::....g".chars().collect();
+        for _ in 0..20_000 {
+            let len = (next() % 24) as usize;
+            let s: String = (0..len).map(|_| alphabet[(next() % alphabet.len() as u64) as usize]).collect();
+            check_against_std(&s);
+        }
+    }
+
+    // 随机地址的格式化和转换；每段有一半的概率是 0，这样才会经常出现需要压缩的零串
+    #[test]
+    fn matches_std_on_random_ipv6_addresses() {
+        let mut next = xorshift(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..5_000 {
+            let segments: [u16; 8] = std::array::from_fn(|_| if next() % 2 == 0 { 0 } else { next() as u16 });
+            let addr = IpAddr::V6(segments);
+            assert_eq!(addr.to_string(), net::Ipv6Addr::from(segments).to_string());
+            assert_eq!(IpAddr::parse(&addr.to_string()), Ok(addr));
+            assert_eq!(IpAddr::from(net::IpAddr::from(addr)), addr);
+        }
+    }
+}
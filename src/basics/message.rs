@@ -0,0 +1,578 @@
+use std::fmt;
+
+use super::color::Color;
+
+// 消息脚本解释器（Message Interpreter）
+// `enums.rs` 中的 `Message` 定义在函数内部，`call()` 只会打印。
+// 这里把它提到模块级别，再配上一个小小的解释器：
+// 1. 解析：把文本脚本逐行解析成 `Message` 值，出错时报告行号。
+// 2. 执行：在一块画布上移动“海龟”（turtle），移动时留下轨迹，`Write` 在当前位置写字，
+//    `ChangeColor` 换笔的颜色，`Quit` 停止执行。
+// 3. 渲染：把画布的最终状态画成文本。
+// 每一步都是对 `Message` 的一次 `match`，整个解释器就是一个由枚举驱动的状态机。
+//
+// 脚本格式，每行一条消息，`#` 开头的行是注释：
+//
+//     move 10 2
+//     color 255 136 0
+//     write hello
+//     write "  padded  "
+//     quit
+//
+// `write` 后面的文本原样保留，只有行尾的空白会被去掉。文本为空、以空白结尾、以 `"` 开头
+// 或者包含换行这类控制字符时，用双引号括起来，里面支持 `\"`、`\\`、`\n`、`\r`、`\t` 和 `\u{..}` 转义。
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(i32, i32, i32),
+}
+
+// 反过来把消息写成脚本中的一行，`parse_line` 能原样读回。
+// 不加引号就无法原样读回的文本（见上面的脚本格式）写成带转义的引号形式
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Message::Quit => write!(f, "quit"),
+            Message::Move { x, y } => write!(f, "move {} {}", x, y),
+            Message::Write(text) if needs_quotes(text) => write!(f, "write {}", quote(text)),
+            Message::Write(text) => write!(f, "write {}", text),
+            Message::ChangeColor(r, g, b) => write!(f, "color {} {} {}", r, g, b),
+        }
+    }
+}
+
+// 空文本和行尾的空白会被 `parse_line` 去掉，换行会把一条消息拆成两行，
+// 以 `"` 开头的文本会被当成引号形式
+fn needs_quotes(text: &str) -> bool {
+    text.is_empty()
+        || text.starts_with('"')
+        || text.ends_with(char::is_whitespace)
+        || text.contains(char::is_control)
+}
+
+fn quote(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{{{:x}}}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+// 读取 `quote` 写出的引号形式，右引号后面不能再有内容
+fn unquote(quoted: &str) -> Result<String, ParseErrorKind> {
+    let invalid = || ParseErrorKind::InvalidQuotedText(quoted.to_string());
+    let mut chars = quoted.strip_prefix('"').ok_or_else(invalid)?.chars();
+    let mut text = String::new();
+    loop {
+        match chars.next().ok_or_else(invalid)? {
+            '"' => break,
+            '\\' => {
+                let escaped = match chars.next().ok_or_else(invalid)? {
+                    '"' => '"',
+                    '\\' => '\\',
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    'u' => {
+                        let rest = chars.as_str().strip_prefix('{').ok_or_else(invalid)?;
+                        let (hex, after) = rest.split_once('}').ok_or_else(invalid)?;
+                        let code = u32::from_str_radix(hex, 16).map_err(|_| invalid())?;
+                        chars = after.chars();
+                        char::from_u32(code).ok_or_else(invalid)?
+                    }
+                    _ => return Err(invalid()),
+                };
+                text.push(escaped);
+            }
+            c => text.push(c),
+        }
+    }
+    if !chars.as_str().is_empty() {
+        return Err(invalid());
+    }
+    Ok(text)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    UnknownCommand(String),
+    WrongArgumentCount {
+        command: &'static str,
+        expected: usize,
+        found: usize,
+    },
+    InvalidNumber(String),
+    // 颜色通道必须在 0..=255 之间
+    ColorOutOfRange(i32),
+    // `write "..."` 的引号没有闭合、转义不合法，或者右引号后面还有内容
+    InvalidQuotedText(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptError {
+    // 从 1 开始的行号
+    pub line: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            ParseErrorKind::UnknownCommand(command) => write!(f, "unknown command {:?}", command),
+            ParseErrorKind::WrongArgumentCount { command, expected, found } => {
+                write!(f, "`{}` takes {} argument(s), found {}", command, expected, found)
+            }
+            ParseErrorKind::InvalidNumber(s) => write!(f, "invalid number {:?}", s),
+            ParseErrorKind::ColorOutOfRange(n) => write!(f, "color channel {} is not in 0..=255", n),
+            ParseErrorKind::InvalidQuotedText(s) => write!(f, "invalid quoted text {}", s),
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+fn numbers<const N: usize>(command: &'static str, args: &str) -> Result<[i32; N], ParseErrorKind> {
+    let words: Vec<&str> = args.split_whitespace().collect();
+    if words.len() != N {
+        return Err(ParseErrorKind::WrongArgumentCount { command, expected: N, found: words.len() });
+    }
+    let mut values = [0; N];
+    for (value, word) in values.iter_mut().zip(words) {
+        *value = word.parse().map_err(|_| ParseErrorKind::InvalidNumber(word.to_string()))?;
+    }
+    Ok(values)
+}
+
+// 解析一行。空行和注释返回 `Ok(None)`
+pub fn parse_line(line: &str) -> Result<Option<Message>, ParseErrorKind> {
+    let line = line.trim_end();
+    let trimmed = line.trim_start();
+    if trimmed.is_empty() || trimmed.starts_with('#') {
+        return Ok(None);
+    }
+
+    let (command, args) = trimmed.split_once(char::is_whitespace).unwrap_or((trimmed, ""));
+    let message = match command {
+        "quit" => {
+            let [] = numbers("quit", args)?;
+            Message::Quit
+        }
+        "move" => {
+            let [x, y] = numbers("move", args)?;
+            Message::Move { x, y }
+        }
+        "write" => {
+            // 命令后面的第一个空白只是分隔符，其余部分原样作为文本
+            if args.is_empty() {
+                return Err(ParseErrorKind::WrongArgumentCount { command: "write", expected: 1, found: 0 });
+            }
+            if args.starts_with('"') {
+                Message::Write(unquote(args)?)
+            } else {
+                Message::Write(args.to_string())
+            }
+        }
+        "color" => {
+            let [r, g, b] = numbers("color", args)?;
+            if let Some(&channel) = [r, g, b].iter().find(|c| !(0..=255).contains(*c)) {
+                return Err(ParseErrorKind::ColorOutOfRange(channel));
+            }
+            Message::ChangeColor(r, g, b)
+        }
+        other => return Err(ParseErrorKind::UnknownCommand(other.to_string())),
+    };
+    Ok(Some(message))
+}
+
+// 解析整个脚本。不会在第一个错误处停下，而是收集所有出错的行一起报告
+pub fn parse_script(script: &str) -> Result<Vec<Message>, Vec<ScriptError>> {
+    let mut messages = Vec::new();
+    let mut errors = Vec::new();
+
+    for (index, line) in script.lines().enumerate() {
+        match parse_line(line) {
+            Ok(Some(message)) => messages.push(message),
+            Ok(None) => {}
+            Err(kind) => errors.push(ScriptError { line: index + 1, kind }),
+        }
+    }
+
+    if errors.is_empty() { Ok(messages) } else { Err(errors) }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    pub ch: char,
+    pub color: Color,
+}
+
+// 画布：海龟的位置、笔的颜色、画过的格子，以及是否已经收到 `Quit`
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    cells: Vec<Option<Cell>>,
+    pub position: (i32, i32),
+    pub pen: Color,
+    pub written: Vec<String>,
+    pub stopped: bool,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize) -> Canvas {
+        Canvas {
+            width,
+            height,
+            cells: vec![None; width * height],
+            position: (0, 0),
+            pen: Color::WHITE,
+            written: Vec::new(),
+            stopped: false,
+        }
+    }
+
+    pub fn cell(&self, x: i32, y: i32) -> Option<Cell> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        self.cells[y as usize * self.width + x as usize]
+    }
+
+    // 画布外的格子直接忽略
+    fn plot(&mut self, x: i32, y: i32, ch: char) {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return;
+        }
+        let cell = &mut self.cells[y as usize * self.width + x as usize];
+        // 两条不同方向的线相交处画成 `+`
+        let ch = match cell {
+            Some(old) if old.ch != ch && "-|/\\+".contains(old.ch) && "-|/\\".contains(ch) => '+',
+            _ => ch,
+        };
+        *cell = Some(Cell { ch, color: self.pen });
+    }
+
+    // 海龟碰到画布边缘就停下，所以目标坐标先截断到画布范围内
+    fn clamp(&self, x: i32, y: i32) -> (i32, i32) {
+        let max_x = self.width.saturating_sub(1) as i32;
+        let max_y = self.height.saturating_sub(1) as i32;
+        (x.clamp(0, max_x), y.clamp(0, max_y))
+    }
+
+    // Bresenham 直线算法：只用整数运算，每一步沿 x、y 或对角线方向前进一格。
+    // 整条线用同一个字符，按斜率选 `-`、`|`、`\` 或 `/`（y 轴向下）
+    fn draw_line(&mut self, to: (i32, i32)) {
+        let (mut x, mut y) = self.position;
+        let (dx, dy) = ((to.0 - x).abs(), -(to.1 - y).abs());
+        let (sx, sy) = ((to.0 - x).signum(), (to.1 - y).signum());
+        let ch = if dx > -2 * dy {
+            '-'
+        } else if -dy > 2 * dx {
+            '|'
+        } else if sx == sy {
+            '\\'
+        } else {
+            '/'
+        };
+
+        let mut error = dx + dy;
+        self.plot(x, y, ch);
+        while (x, y) != to {
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                x += sx;
+            }
+            if doubled <= dx {
+                error += dx;
+                y += sy;
+            }
+            self.plot(x, y, ch);
+        }
+    }
+
+    // 执行一条消息。收到 `Quit` 之后的消息都被忽略，返回值表示这条消息是否被执行了。
+    // 颜色超出 0..=255 的 `ChangeColor` 会被拒绝，笔的颜色保持不变
+    pub fn execute(&mut self, message: &Message) -> bool {
+        if self.stopped {
+            return false;
+        }
+        match message {
+            Message::Quit => self.stopped = true,
+            Message::Move { x, y } => {
+                let target = self.clamp(*x, *y);
+                self.draw_line(target);
+                self.position = target;
+            }
+            Message::Write(text) => {
+                // 从当前位置向右写，写完后海龟停在最后一个字符的后面
+                let (x, y) = self.position;
+                for (offset, ch) in text.chars().enumerate() {
+                    self.plot(x + offset as i32, y, ch);
+                }
+                let end = x.saturating_add(text.chars().count() as i32);
+                self.position = self.clamp(end, y);
+                self.written.push(text.clone());
+            }
+            // `Message` 是公开的，可以不经过解析器直接构造，所以这里还要再检查一次范围。
+            // `as u8` 会悄悄截断（300 变成 44，-1 变成 255），`u8::try_from` 则会报告失败
+            Message::ChangeColor(r, g, b) => {
+                let (Ok(r), Ok(g), Ok(b)) = (u8::try_from(*r), u8::try_from(*g), u8::try_from(*b)) else {
+                    return false;
+                };
+                self.pen = Color(r, g, b);
+            }
+        }
+        true
+    }
+
+    // 依次执行，返回实际执行了多少条。被拒绝的消息会跳过，不影响后面的消息
+    pub fn run(&mut self, messages: &[Message]) -> usize {
+        messages.iter().filter(|message| self.execute(message)).count()
+    }
+
+    // 纯文本渲染：空格子是 `.`，海龟所在的格子如果是空的就画成 `@`
+    pub fn render(&self) -> String {
+        self.render_with(|cell| cell.ch.to_string())
+    }
+
+    // 彩色渲染：每个格子用它被画上时的笔色作为前景色
+    pub fn render_colored(&self) -> String {
+        self.render_with(|cell| {
+            let Color(r, g, b) = cell.color;
+            format!("\x1b[38;2;{};{};{}m{}\x1b[0m", r, g, b, cell.ch)
+        })
+    }
+
+    fn render_with(&self, draw: impl Fn(Cell) -> String) -> String {
+        let mut out = String::new();
+        for y in 0..self.height as i32 {
+            for x in 0..self.width as i32 {
+                match self.cell(x, y) {
+                    Some(cell) => out.push_str(&draw(cell)),
+                    None if (x, y) == self.position => out.push('@'),
+                    None => out.push('.'),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+const BOX_SCRIPT: &str = "\
+# 画一个盒子，再在里面写字
+move 11 0
+color 255 136 0
+move 11 4
+move 0 4
+color 0 200 255
+move 0 0
+move 2 2
+write hi Rust
+quit
+write never shown
+";
+
+pub fn message_example() {
+    // 解析脚本
+    let messages = parse_script(BOX_SCRIPT).unwrap();
+    println!("parsed {} messages, first: {:?}", messages.len(), messages[0]);
+
+    // 执行：`Quit` 之后的消息不会执行
+    let mut canvas = Canvas::new(12, 5);
+    let executed = canvas.run(&messages);
+    print!("{}", canvas.render_colored());
+    println!("executed {}, turtle at {:?}, wrote {:?}", executed, canvas.position, canvas.written);
+
+    // 直接构造的消息绕过了解析器的检查，执行时同样会被拒绝
+    let mut direct = Canvas::new(4, 3);
+    println!("ChangeColor(300, -1, 0) executed: {}", direct.execute(&Message::ChangeColor(300, -1, 0)));
+
+    // 每条消息都能写回脚本。空文本、首尾的空白和换行用引号保留下来
+    let texts = ["hi Rust", "", "two\nlines "];
+    for message in texts.map(|text| Message::Write(text.to_string())) {
+        println!("{:<28} <- {:?}", message.to_string(), message);
+    }
+
+    // 解析错误带有行号，而且会一次报告所有错误
+    let broken = "move 1\nmove 1 2\njump 3 4\n\ncolor 0 300 0\nmove one 2\nwrite\nquit now\nwrite \"open";
+    for error in parse_script(broken).unwrap_err() {
+        println!("{}", error);
+    }
+}
+
+// 课程注册，见 lesson.rs
+pub struct MessageLesson;
+
+impl crate::lesson::Lesson for MessageLesson {
+    fn title(&self) -> &'static str {
+        "Message Interpreter"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("message.rs")
+    }
+
+    fn run(&self) {
+        message_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_script() {
+        let messages = parse_script(BOX_SCRIPT).unwrap();
+        assert_eq!(messages.len(), 10);
+        assert_eq!(messages[0], Message::Move { x: 11, y: 0 });
+        assert_eq!(messages[1], Message::ChangeColor(255, 136, 0));
+        assert_eq!(messages[7], Message::Write(String::from("hi Rust")));
+        assert_eq!(messages[8], Message::Quit);
+        assert_eq!(parse_line("   # indented comment"), Ok(None));
+        // 命令后的第一个空白是分隔符，行尾的空白被去掉
+        assert_eq!(parse_line("write   spaced  out "), Ok(Some(Message::Write(String::from("  spaced  out")))));
+    }
+
+    #[test]
+    fn script_messages_round_trip() {
+        for message in parse_script(BOX_SCRIPT).unwrap() {
+            assert_eq!(parse_line(&message.to_string()), Ok(Some(message.clone())));
+        }
+    }
+
+    // 不加引号时空文本会被当成缺少参数，行尾的空白会被去掉
+    #[test]
+    fn empty_text_round_trips() {
+        let empty = Message::Write(String::new());
+        assert_eq!(empty.to_string(), "write \"\"");
+        assert_eq!(parse_line(&empty.to_string()), Ok(Some(empty)));
+    }
+
+    #[test]
+    fn trailing_whitespace_round_trips() {
+        let padded = Message::Write(String::from("trailing  "));
+        assert_eq!(padded.to_string(), "write \"trailing  \"");
+        assert_eq!(parse_line(&padded.to_string()), Ok(Some(padded)));
+    }
+
+    #[test]
+    fn unusual_text_round_trips() {
+        let texts = [
+            "  both  ", " ", "\t", "two\nlines", "cr\r", "\"quoted\"", "\"", "back\\slash", "bell\u{7}",
+            "a \"b\" c", "  leading", "中文 ", "\u{3000}", "#hashtag", "write",
+        ];
+        for text in texts {
+            let message = Message::Write(text.to_string());
+            let line = message.to_string();
+            assert!(!line.contains('\n'), "{:?}", line);
+            assert_eq!(parse_line(&line), Ok(Some(message)), "{:?}", line);
+            // 放进整个脚本里也一样
+            assert_eq!(parse_script(&format!("{}\nquit", line)).map(|m| m.len()), Ok(2), "{:?}", line);
+        }
+        // 不需要引号的文本保持原样
+        assert_eq!(Message::Write(String::from("a \"b\" c")).to_string(), "write a \"b\" c");
+    }
+
+    #[test]
+    fn invalid_quoted_text() {
+        for line in ["write \"open", "write \"a\" b", "write \"bad \\q\"", "write \"\\u{zz}\"", "write \"\\u{d800}\""] {
+            let quoted = line.strip_prefix("write ").unwrap().to_string();
+            assert_eq!(parse_line(line), Err(ParseErrorKind::InvalidQuotedText(quoted)), "{}", line);
+        }
+        assert_eq!(parse_line("write \"\\u{4e2d}\\t\""), Ok(Some(Message::Write(String::from("中\t")))));
+    }
+
+    #[test]
+    fn runs_until_quit() {
+        let messages = parse_script(BOX_SCRIPT).unwrap();
+        let mut canvas = Canvas::new(12, 5);
+        assert_eq!(canvas.run(&messages), 9);
+        assert!(canvas.stopped);
+        assert_eq!(canvas.written, vec![String::from("hi Rust")]);
+        assert_eq!(canvas.position, (9, 2));
+        assert_eq!(canvas.pen, Color(0, 200, 255));
+        assert_eq!(canvas.cell(11, 2).map(|cell| cell.color), Some(Color(255, 136, 0)));
+        assert!(!canvas.execute(&Message::Move { x: 0, y: 0 }));
+        assert_eq!(
+            canvas.render(),
+            "\
++----------+
+|\\.........|
+|.hi Rust@.|
+|..........|
++----------+
+"
+        );
+    }
+
+    // 直接构造的消息绕过了解析器的检查，执行时同样会被拒绝
+    #[test]
+    fn rejects_out_of_range_colors() {
+        let mut direct = Canvas::new(4, 3);
+        assert!(!direct.execute(&Message::ChangeColor(300, -1, 0)));
+        assert_eq!(direct.pen, Color::WHITE);
+        let mixed = [Message::ChangeColor(0, 0, 256), Message::ChangeColor(10, 20, 30), Message::Write(String::from("ok"))];
+        assert_eq!(direct.run(&mixed), 2);
+        assert_eq!(direct.pen, Color(10, 20, 30));
+    }
+
+    // 超出画布的移动会停在边缘
+    #[test]
+    fn moves_are_clamped_to_canvas() {
+        let mut small = Canvas::new(4, 3);
+        small.execute(&Message::Move { x: 100, y: -100 });
+        assert_eq!(small.position, (3, 0));
+        small.execute(&Message::Move { x: i32::MIN, y: i32::MAX });
+        assert_eq!(small.position, (0, 2));
+        small.execute(&Message::Write(String::from("overflowing text")));
+        assert_eq!(small.position, (3, 2));
+        assert_eq!(small.render(), "---+\n.//.\nover\n");
+    }
+
+    #[test]
+    fn reports_all_errors_with_line_numbers() {
+        let broken = "move 1\nmove 1 2\njump 3 4\n\ncolor 0 300 0\nmove one 2\nwrite\nquit now";
+        let errors = parse_script(broken).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                ScriptError {
+                    line: 1,
+                    kind: ParseErrorKind::WrongArgumentCount { command: "move", expected: 2, found: 1 },
+                },
+                ScriptError { line: 3, kind: ParseErrorKind::UnknownCommand(String::from("jump")) },
+                ScriptError { line: 5, kind: ParseErrorKind::ColorOutOfRange(300) },
+                ScriptError { line: 6, kind: ParseErrorKind::InvalidNumber(String::from("one")) },
+                ScriptError {
+                    line: 7,
+                    kind: ParseErrorKind::WrongArgumentCount { command: "write", expected: 1, found: 0 },
+                },
+                ScriptError {
+                    line: 8,
+                    kind: ParseErrorKind::WrongArgumentCount { command: "quit", expected: 0, found: 1 },
+                },
+            ]
+        );
+        assert_eq!(errors[2].to_string(), "line 5: color channel 300 is not in 0..=255");
+    }
+}
@@ -0,0 +1,346 @@
+use std::fmt;
+use std::str::Utf8Error;
+
+use super::message::{parse_script, Message};
+
+// 消息的二进制编码（Wire Encoding）
+// 枚举天然就是协议定义：每个变体是一种消息，变体携带的数据就是消息的字段。
+// 编码格式：一个标签字节表示变体，后面跟着这个变体的字段，整数一律用大端序（网络字节序）。
+//
+//     Quit                  0x00
+//     Move { x, y }         0x01  x: i32  y: i32
+//     Write(text)           0x02  len: u32  text: [u8; len]（UTF-8）
+//     ChangeColor(r, g, b)  0x03  r: i32  g: i32  b: i32
+//
+// 定长字段直接按宽度读取，变长的字符串前面加上长度，
+// 所以每条消息都能自己界定边界，多条消息可以直接首尾相连地放在一个字节流里。
+
+const TAG_QUIT: u8 = 0x00;
+const TAG_MOVE: u8 = 0x01;
+const TAG_WRITE: u8 = 0x02;
+const TAG_CHANGE_COLOR: u8 = 0x03;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    // 读取某个字段时数据不够了：字段名、它在输入中的位置、需要的字节数和剩下的字节数
+    Truncated {
+        field: &'static str,
+        offset: usize,
+        needed: usize,
+        available: usize,
+    },
+    UnknownTag { tag: u8, offset: usize },
+    InvalidUtf8 { offset: usize, error: Utf8Error },
+    // `decode` 要求输入恰好是一条消息
+    TrailingBytes(usize),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::Truncated { field, offset, needed, available } => write!(
+                f,
+                "truncated {} at byte {}: needed {} byte(s), only {} left",
+                field, offset, needed, available
+            ),
+            DecodeError::UnknownTag { tag, offset } => write!(f, "unknown tag 0x{:02x} at byte {}", tag, offset),
+            DecodeError::InvalidUtf8 { offset, error } => write!(f, "invalid UTF-8 text at byte {}: {}", offset, error),
+            DecodeError::TrailingBytes(n) => write!(f, "{} unexpected byte(s) after the message", n),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    // 超过 4 GiB 的文本无法用 u32 表示长度，这在协议层面是不允许的
+    TextTooLong { len: usize },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EncodeError::TextTooLong { len } => {
+                write!(f, "text of {} bytes does not fit in a u32 length prefix", len)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+// 文本长度写成 u32 长度前缀。单独拿出来，不用真的分配 4 GiB 的字符串就能测试边界
+fn length_prefix(len: usize) -> Result<u32, EncodeError> {
+    u32::try_from(len).map_err(|_| EncodeError::TextTooLong { len })
+}
+
+// 出错时 `out` 保持原样，不会留下写了一半的消息
+pub fn encode_into(message: &Message, out: &mut Vec<u8>) -> Result<(), EncodeError> {
+    match message {
+        Message::Quit => out.push(TAG_QUIT),
+        Message::Move { x, y } => {
+            out.push(TAG_MOVE);
+            out.extend_from_slice(&x.to_be_bytes());
+            out.extend_from_slice(&y.to_be_bytes());
+        }
+        Message::Write(text) => {
+            let len = length_prefix(text.len())?;
+            out.push(TAG_WRITE);
+            out.extend_from_slice(&len.to_be_bytes());
+            out.extend_from_slice(text.as_bytes());
+        }
+        Message::ChangeColor(r, g, b) => {
+            out.push(TAG_CHANGE_COLOR);
+            for channel in [r, g, b] {
+                out.extend_from_slice(&channel.to_be_bytes());
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn encode(message: &Message) -> Result<Vec<u8>, EncodeError> {
+    let mut out = Vec::new();
+    encode_into(message, &mut out)?;
+    Ok(out)
+}
+
+pub fn encode_all(messages: &[Message]) -> Result<Vec<u8>, EncodeError> {
+    let mut out = Vec::new();
+    for message in messages {
+        encode_into(message, &mut out)?;
+    }
+    Ok(out)
+}
+
+// 按顺序读取字节，记住读到了哪里，出错时能报告准确的位置
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, field: &'static str, needed: usize) -> Result<&'a [u8], DecodeError> {
+        let available = self.bytes.len() - self.position;
+        if available < needed {
+            return Err(DecodeError::Truncated { field, offset: self.position, needed, available });
+        }
+        let bytes = &self.bytes[self.position..self.position + needed];
+        self.position += needed;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self, field: &'static str) -> Result<u8, DecodeError> {
+        Ok(self.take(field, 1)?[0])
+    }
+
+    // `take` 已经保证了长度正好是 4，所以 `try_into` 不会失败
+    fn read_u32(&mut self, field: &'static str) -> Result<u32, DecodeError> {
+        Ok(u32::from_be_bytes(self.take(field, 4)?.try_into().unwrap()))
+    }
+
+    fn read_i32(&mut self, field: &'static str) -> Result<i32, DecodeError> {
+        Ok(i32::from_be_bytes(self.take(field, 4)?.try_into().unwrap()))
+    }
+
+    fn read_message(&mut self) -> Result<Message, DecodeError> {
+        let offset = self.position;
+        let message = match self.read_u8("tag")? {
+            TAG_QUIT => Message::Quit,
+            TAG_MOVE => Message::Move {
+                x: self.read_i32("Move.x")?,
+                y: self.read_i32("Move.y")?,
+            },
+            TAG_WRITE => {
+                let len = self.read_u32("Write length")? as usize;
+                let text_offset = self.position;
+                let bytes = self.take("Write text", len)?;
+                let text = std::str::from_utf8(bytes)
+                    .map_err(|error| DecodeError::InvalidUtf8 { offset: text_offset, error })?;
+                Message::Write(text.to_string())
+            }
+            TAG_CHANGE_COLOR => Message::ChangeColor(
+                self.read_i32("ChangeColor.r")?,
+                self.read_i32("ChangeColor.g")?,
+                self.read_i32("ChangeColor.b")?,
+            ),
+            tag => return Err(DecodeError::UnknownTag { tag, offset }),
+        };
+        Ok(message)
+    }
+}
+
+// 从字节流的开头解码一条消息，返回消息和它占用的字节数
+pub fn decode_prefix(bytes: &[u8]) -> Result<(Message, usize), DecodeError> {
+    let mut reader = Reader { bytes, position: 0 };
+    let message = reader.read_message()?;
+    Ok((message, reader.position))
+}
+
+// 解码恰好一条消息，后面多出来的字节视为错误
+pub fn decode(bytes: &[u8]) -> Result<Message, DecodeError> {
+    let (message, used) = decode_prefix(bytes)?;
+    if used < bytes.len() {
+        return Err(DecodeError::TrailingBytes(bytes.len() - used));
+    }
+    Ok(message)
+}
+
+// 解码首尾相连的多条消息，错误中的位置相对于整个字节流
+pub fn decode_all(bytes: &[u8]) -> Result<Vec<Message>, DecodeError> {
+    let mut reader = Reader { bytes, position: 0 };
+    let mut messages = Vec::new();
+    while reader.position < bytes.len() {
+        messages.push(reader.read_message()?);
+    }
+    Ok(messages)
+}
+
+pub fn message_codec_example() {
+    // 每个变体都能编码成字节再解码回来
+    let messages = vec![
+        Message::Quit,
+        Message::Move { x: 3, y: -4 },
+        Message::Write(String::from("你好，Rust 🦀")),
+        Message::ChangeColor(255, 136, 0),
+    ];
+    for message in &messages {
+        let bytes = encode(message).unwrap();
+        println!("{:<28} -> {:02x?}", message.to_string(), bytes);
+        println!("{:<28} <- {:?}", "", decode(&bytes));
+    }
+
+    // 多条消息首尾相连；和 message.rs 的文本脚本互相转换
+    let script = parse_script("move 2 3\ncolor 0 200 255\nwrite ok\nquit").unwrap();
+    let wire = encode_all(&script).unwrap();
+    println!("script: {} messages, {} bytes", script.len(), wire.len());
+
+    // 解码错误会指出是哪个字段、在第几个字节
+    for bytes in [&[0x01, 0, 0, 0, 1, 0xff][..], &[0x07], &[0x02, 0, 0, 0, 2, 0xc3, 0x28], &[0x00, 0x00]] {
+        match decode(bytes) {
+            Ok(message) => println!("{:02x?} -> {}", bytes, message),
+            Err(error) => println!("{:02x?} -> error: {}", bytes, error),
+        }
+    }
+
+    // 编码也可能失败：文本长度必须放得进 u32
+    if let Some(len) = (u32::MAX as usize).checked_add(1) {
+        println!("{}", length_prefix(len).unwrap_err());
+    }
+}
+
+// 课程注册，见 lesson.rs
+pub struct MessageCodecLesson;
+
+impl crate::lesson::Lesson for MessageCodecLesson {
+    fn title(&self) -> &'static str {
+        "Wire Encoding"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("message_codec.rs")
+    }
+
+    fn run(&self) {
+        message_codec_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples() -> Vec<Message> {
+        vec![
+            Message::Quit,
+            Message::Move { x: 3, y: -4 },
+            Message::Move { x: i32::MIN, y: i32::MAX },
+            Message::Write(String::from("hello")),
+            Message::Write(String::new()),
+            Message::Write(String::from("你好，Rust 🦀")),
+            Message::ChangeColor(255, 136, 0),
+            Message::ChangeColor(-1, 0, 1000),
+        ]
+    }
+
+    #[test]
+    fn every_variant_round_trips() {
+        for message in samples() {
+            let bytes = encode(&message).unwrap();
+            assert_eq!(decode(&bytes), Ok(message.clone()));
+            assert_eq!(decode_prefix(&bytes), Ok((message, bytes.len())));
+        }
+    }
+
+    // 字节布局是固定的，其他语言的实现可以按这个格式读写
+    #[test]
+    fn byte_layout() {
+        assert_eq!(encode(&Message::Quit), Ok(vec![0x00]));
+        assert_eq!(encode(&Message::Move { x: 1, y: -1 }), Ok(vec![0x01, 0, 0, 0, 1, 0xff, 0xff, 0xff, 0xff]));
+        assert_eq!(encode(&Message::Write(String::from("hi"))), Ok(vec![0x02, 0, 0, 0, 2, b'h', b'i']));
+        assert_eq!(encode(&Message::ChangeColor(1, 2, 3)).map(|bytes| bytes.len()), Ok(13));
+    }
+
+    #[test]
+    fn streams_round_trip() {
+        let messages = samples();
+        let stream = encode_all(&messages).unwrap();
+        assert_eq!(decode_all(&stream), Ok(messages));
+        assert_eq!(decode_all(&[]), Ok(Vec::new()));
+
+        let script = parse_script("move 2 3\ncolor 0 200 255\nwrite ok\nquit").unwrap();
+        assert_eq!(decode_all(&encode_all(&script).unwrap()), Ok(script));
+    }
+
+    // 长度前缀的边界：u32::MAX 字节还能表示，再多一个字节就不行了
+    #[test]
+    fn length_prefix_boundary() {
+        assert_eq!(length_prefix(0), Ok(0));
+        assert_eq!(length_prefix(u32::MAX as usize), Ok(u32::MAX));
+        // 32 位平台上 usize 放不下 u32::MAX + 1，也就不可能出现这么长的文本
+        if let Some(len) = (u32::MAX as usize).checked_add(1) {
+            let error = length_prefix(len).unwrap_err();
+            assert_eq!(error, EncodeError::TextTooLong { len });
+            assert_eq!(error.to_string(), format!("text of {} bytes does not fit in a u32 length prefix", len));
+        }
+    }
+
+    // 截断：任何一个不完整的前缀都会报告缺了哪个字段
+    #[test]
+    fn every_truncated_prefix_is_reported() {
+        for message in samples() {
+            let bytes = encode(&message).unwrap();
+            for end in 0..bytes.len() {
+                assert!(matches!(decode(&bytes[..end]), Err(DecodeError::Truncated { .. })));
+            }
+        }
+    }
+
+    #[test]
+    fn truncation_names_the_field() {
+        let truncated = decode(&[0x01, 0, 0, 0, 1, 0xff]).unwrap_err();
+        assert_eq!(truncated, DecodeError::Truncated { field: "Move.y", offset: 5, needed: 4, available: 1 });
+        assert_eq!(truncated.to_string(), "truncated Move.y at byte 5: needed 4 byte(s), only 1 left");
+        assert_eq!(
+            decode(&[0x02, 0, 0, 0, 10, b'a']),
+            Err(DecodeError::Truncated { field: "Write text", offset: 5, needed: 10, available: 1 })
+        );
+        assert_eq!(decode(&[]), Err(DecodeError::Truncated { field: "tag", offset: 0, needed: 1, available: 0 }));
+    }
+
+    // 未知标签、非法 UTF-8、多余的字节
+    #[test]
+    fn other_decode_errors() {
+        assert_eq!(decode(&[0x07]), Err(DecodeError::UnknownTag { tag: 0x07, offset: 0 }));
+        assert_eq!(decode_all(&[0x00, 0x00, 0xff]), Err(DecodeError::UnknownTag { tag: 0xff, offset: 2 }));
+        let invalid = decode(&[0x02, 0, 0, 0, 2, 0xc3, 0x28]).unwrap_err();
+        assert!(matches!(invalid, DecodeError::InvalidUtf8 { offset: 5, .. }));
+        assert_eq!(decode(&[0x00, 0x00]), Err(DecodeError::TrailingBytes(1)));
+        assert_eq!(decode_prefix(&[0x00, 0x00]), Ok((Message::Quit, 1)));
+    }
+}
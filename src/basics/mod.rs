@@ -3,4 +3,12 @@ pub mod functions;
 pub mod control_flow;
 pub mod ownership;
 pub mod structs;
-pub mod enums;
\ No newline at end of file
+pub mod enums;
+pub mod geometry;
+pub mod packing;
+pub mod user;
+pub mod color;
+pub mod message;
+pub mod message_codec;
+pub mod ip;
+pub mod vending;
\ No newline at end of file
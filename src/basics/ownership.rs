@@ -113,4 +113,25 @@ fn change(some_string: &mut String) {
 //     let s = String::from("hello"); // s 是一个新字符串
 //     &s // 我们返回 s 的引用
 // } // 这里 s 离开作用域，它将被丢弃。它的内存会返还
-// // 危险！
\ No newline at end of file
+// // 危险！
+
+// 课程注册，见 lesson.rs
+pub struct OwnershipLesson;
+
+impl crate::lesson::Lesson for OwnershipLesson {
+    fn title(&self) -> &'static str {
+        "Ownership"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("ownership.rs")
+    }
+
+    fn run(&self) {
+        ownership_example()
+    }
+}
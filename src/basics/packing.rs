@@ -0,0 +1,397 @@
+use super::geometry::Rectangle;
+
+// 矩形装箱（Bin Packing）
+// 把一组矩形尽量多地放进一个容器里，矩形之间不能重叠，也不能旋转。
+// 这是 `Rectangle::can_hold` 和 `area` 的实际用途：每一步都在问
+// “这块空地放得下这个矩形吗？”以及“放进去之后浪费了多少面积？”
+//
+// 两种经典的启发式算法：
+// - 货架算法（Shelf）：按高度从高到低排序，一行一行地摆，像往书架上放书。
+// - 断头台算法（Guillotine）：维护一组空闲矩形，每放一个矩形就把所在的空闲矩形
+//   一刀切成两块，就像裁纸刀只能从一边切到另一边。
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Placement {
+    // 在输入列表中的下标
+    pub index: usize,
+    // 放置后的位置和尺寸
+    pub rect: Rectangle,
+}
+
+#[derive(Debug, Clone)]
+pub struct Packing {
+    pub container: Rectangle,
+    pub placed: Vec<Placement>,
+    // 放不下的矩形在输入列表中的下标
+    pub unplaced: Vec<usize>,
+}
+
+impl Packing {
+    pub fn placed_area(&self) -> u64 {
+        self.placed.iter().map(|p| p.rect.area()).sum()
+    }
+
+    // 利用率：已放置的面积占容器面积的比例
+    pub fn utilization(&self) -> f64 {
+        match self.container.area() {
+            0 => 0.0,
+            total => self.placed_area() as f64 / total as f64,
+        }
+    }
+
+    // 检查结果是否合法：所有矩形都在容器内、两两不重叠、每个输入恰好出现一次
+    pub fn is_valid(&self, items: &[Rectangle]) -> bool {
+        let inside = self.placed.iter().all(|p| {
+            p.rect.is_empty() || self.container.intersection(&p.rect) == Some(p.rect)
+        });
+        let sizes_match = self.placed.iter().all(|p| {
+            let item = items[p.index];
+            (p.rect.width, p.rect.height) == (item.width, item.height)
+        });
+        let disjoint = self.placed.iter().enumerate().all(|(i, a)| {
+            self.placed[i + 1..].iter().all(|b| !a.rect.overlaps(&b.rect))
+        });
+
+        let mut seen: Vec<usize> = self
+            .placed
+            .iter()
+            .map(|p| p.index)
+            .chain(self.unplaced.iter().copied())
+            .collect();
+        seen.sort();
+        let complete = seen == (0..items.len()).collect::<Vec<_>>();
+
+        inside && sizes_match && disjoint && complete
+    }
+
+    // 把结果画成 ASCII 网格，每个单位一个字符：
+    // 每个矩形用一个字母表示（A、B、C……），`.` 表示空地。
+    pub fn render(&self) -> String {
+        const LABELS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+        let width = self.container.width as usize;
+        let height = self.container.height as usize;
+        let mut grid = vec![vec!['.'; width]; height];
+
+        for placement in &self.placed {
+            let label = LABELS[placement.index % LABELS.len()] as char;
+            let left = (placement.rect.left() - self.container.left()) as usize;
+            let top = (placement.rect.top() - self.container.top()) as usize;
+            for row in grid.iter_mut().skip(top).take(placement.rect.height as usize) {
+                for cell in row.iter_mut().skip(left).take(placement.rect.width as usize) {
+                    *cell = label;
+                }
+            }
+        }
+
+        let mut out = String::new();
+        for row in grid {
+            out.extend(row);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+// 按某个键从大到小排列输入的下标，键相同时保持原来的顺序
+fn order_by<K: Ord>(items: &[Rectangle], key: impl Fn(&Rectangle) -> K) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by(|&a, &b| key(&items[b]).cmp(&key(&items[a])));
+    order
+}
+
+struct Shelf {
+    y: i64,
+    height: u32,
+    used: u32,
+}
+
+// 货架上下一个矩形的左上角。和 geometry.rs 一样先在 i64 里计算，
+// 容器靠近 i32::MAX 或者宽度超过 i32::MAX 时，这个位置可能无法用 i32 表示，此时返回 None
+fn shelf_origin(container: Rectangle, shelf: &Shelf) -> Option<(i32, i32)> {
+    let x = i32::try_from(container.left() + shelf.used as i64).ok()?;
+    let y = i32::try_from(shelf.y).ok()?;
+    Some((x, y))
+}
+
+// 货架算法（First-Fit Decreasing Height）：
+// 按高度从高到低处理，每个矩形放进第一个放得下的货架；
+// 都放不下时，如果容器还有剩余高度，就在最上面的货架之上开一个新货架。
+pub fn shelf_pack(container: Rectangle, items: &[Rectangle]) -> Packing {
+    let mut packing = Packing {
+        container,
+        placed: Vec::new(),
+        unplaced: Vec::new(),
+    };
+    let mut shelves: Vec<Shelf> = Vec::new();
+    let mut next_y = container.top();
+
+    for index in order_by(items, |item| (item.height, item.width)) {
+        let item = items[index];
+        let fits = |shelf: &Shelf| {
+            Rectangle::sized(container.width - shelf.used, shelf.height).can_hold(&item)
+                && shelf_origin(container, shelf).is_some()
+        };
+
+        let shelf = match shelves.iter().position(fits) {
+            Some(position) => Some(position),
+            None => {
+                let remaining = (container.bottom() - next_y) as u32;
+                let candidate = Shelf { y: next_y, height: item.height, used: 0 };
+                if Rectangle::sized(container.width, remaining).can_hold(&item)
+                    && shelf_origin(container, &candidate).is_some()
+                {
+                    next_y += item.height as i64;
+                    shelves.push(candidate);
+                    Some(shelves.len() - 1)
+                } else {
+                    None
+                }
+            }
+        };
+
+        // 能选中的货架都检查过起点，这里的 shelf_origin 一定是 Some
+        let slot = shelf.and_then(|position| Some((position, shelf_origin(container, &shelves[position])?)));
+        match slot {
+            Some((position, (x, y))) => {
+                packing.placed.push(Placement {
+                    index,
+                    rect: Rectangle::new(x, y, item.width, item.height),
+                });
+                shelves[position].used += item.width;
+            }
+            None => packing.unplaced.push(index),
+        }
+    }
+
+    packing.unplaced.sort();
+    packing
+}
+
+// 断头台算法：
+// 按面积从大到小处理，每个矩形放进“最合适”的空闲矩形（放进去后剩余面积最小，Best Area Fit），
+// 放在空闲矩形的左上角，然后把剩下的 L 形区域沿较短的一边切成两个新的空闲矩形。
+pub fn guillotine_pack(container: Rectangle, items: &[Rectangle]) -> Packing {
+    let mut packing = Packing {
+        container,
+        placed: Vec::new(),
+        unplaced: Vec::new(),
+    };
+    let mut free = vec![container];
+
+    for index in order_by(items, |item| (item.area(), item.width.max(item.height))) {
+        let item = items[index];
+        let best = free
+            .iter()
+            .enumerate()
+            .filter(|(_, space)| space.can_hold(&item))
+            .min_by_key(|(_, space)| space.area() - item.area())
+            .map(|(position, _)| position);
+
+        let Some(position) = best else {
+            packing.unplaced.push(index);
+            continue;
+        };
+
+        let space = free.swap_remove(position);
+        packing.placed.push(Placement {
+            index,
+            rect: Rectangle::new(space.x, space.y, item.width, item.height),
+        });
+
+        // 右边剩余的宽度和下边剩余的高度
+        let leftover_width = space.width - item.width;
+        let leftover_height = space.height - item.height;
+        // 切点在 i64 里计算。起点超出 i32 的空闲矩形无法表示，也不可能再放下任何矩形，直接丢弃
+        let right_x = i32::try_from(space.left() + item.width as i64).ok();
+        let below_y = i32::try_from(space.top() + item.height as i64).ok();
+
+        let (right, below) = if leftover_width < leftover_height {
+            // 水平切：下方得到整条宽度
+            (
+                right_x.map(|x| Rectangle::new(x, space.y, leftover_width, item.height)),
+                below_y.map(|y| Rectangle::new(space.x, y, space.width, leftover_height)),
+            )
+        } else {
+            // 垂直切：右侧得到整条高度
+            (
+                right_x.map(|x| Rectangle::new(x, space.y, leftover_width, space.height)),
+                below_y.map(|y| Rectangle::new(space.x, y, item.width, leftover_height)),
+            )
+        };
+        free.extend([right, below].into_iter().flatten().filter(|r| !r.is_empty()));
+    }
+
+    packing.unplaced.sort();
+    packing
+}
+
+pub fn packing_example() {
+    // 矩形装箱
+    // 把几块不同尺寸的板材放进一个 20x10 的容器里。
+    let container = Rectangle::sized(20, 10);
+    let items: Vec<Rectangle> = ["8x4", "6x6", "5x3", "4x4", "7x2", "3x3", "10x3", "2x9"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+
+    let total: u64 = items.iter().map(Rectangle::area).sum();
+    println!("{} items, total area {} in a {} container", items.len(), total, container);
+
+    for (name, packing) in [
+        ("Shelf", shelf_pack(container, &items)),
+        ("Guillotine", guillotine_pack(container, &items)),
+    ] {
+        println!("\n{} packing:", name);
+        print!("{}", packing.render());
+        println!(
+            "placed {}/{}, utilization {:.1}%, unplaced: {:?}",
+            packing.placed.len(),
+            items.len(),
+            packing.utilization() * 100.0,
+            packing.unplaced
+        );
+        println!("valid: {}", packing.is_valid(&items));
+    }
+
+    // can_hold 不是严格比较，所以和容器一样大的矩形正好放得下
+    let exact = shelf_pack(container, &[container]);
+    println!("\nsame size as the container: utilization {:.1}%", exact.utilization() * 100.0);
+}
+
+// 课程注册，见 lesson.rs
+pub struct PackingLesson;
+
+impl crate::lesson::Lesson for PackingLesson {
+    fn title(&self) -> &'static str {
+        "Bin Packing"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("packing.rs")
+    }
+
+    fn run(&self) {
+        packing_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn both(container: Rectangle, items: &[Rectangle]) -> [Packing; 2] {
+        [shelf_pack(container, items), guillotine_pack(container, items)]
+    }
+
+    #[test]
+    fn example_items_are_packed_validly() {
+        let container = Rectangle::sized(20, 10);
+        let items: Vec<Rectangle> = ["8x4", "6x6", "5x3", "4x4", "7x2", "3x3", "10x3", "2x9"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        for packing in both(container, &items) {
+            assert!(packing.is_valid(&items));
+        }
+    }
+
+    // can_hold 不是严格比较，所以和容器一样大的矩形正好放得下
+    #[test]
+    fn item_as_large_as_container_fits() {
+        let container = Rectangle::sized(20, 10);
+        let exact = [container];
+        for packing in both(container, &exact) {
+            assert!(packing.is_valid(&exact));
+            assert_eq!(packing.unplaced, Vec::<usize>::new());
+            assert_eq!(packing.utilization(), 1.0);
+        }
+    }
+
+    #[test]
+    fn items_larger_than_container_are_unplaced() {
+        let container = Rectangle::sized(20, 10);
+        let too_big = [Rectangle::sized(21, 1), Rectangle::sized(1, 11), Rectangle::sized(2, 2)];
+        for packing in both(container, &too_big) {
+            assert!(packing.is_valid(&too_big));
+            assert_eq!(packing.unplaced, vec![0, 1]);
+        }
+    }
+
+    // 四个 10x5 的矩形恰好铺满容器
+    #[test]
+    fn quarters_fill_the_container() {
+        let container = Rectangle::sized(20, 10);
+        let quarters = [Rectangle::sized(10, 5); 4];
+        for packing in both(container, &quarters) {
+            assert!(packing.is_valid(&quarters));
+            assert_eq!(packing.utilization(), 1.0);
+        }
+    }
+
+    // 容器可以有偏移，放置结果跟着容器走
+    #[test]
+    fn shifted_container() {
+        let shifted = Rectangle::new(-5, 3, 4, 4);
+        let squares = [Rectangle::square(2), Rectangle::square(2)];
+        let packing = guillotine_pack(shifted, &squares);
+        assert!(packing.is_valid(&squares));
+        assert_eq!(packing.placed[0].rect, Rectangle::new(-5, 3, 2, 2));
+    }
+
+    #[test]
+    fn empty_input_and_empty_container() {
+        let container = Rectangle::sized(20, 10);
+        assert!(shelf_pack(container, &[]).is_valid(&[]));
+        let empty = shelf_pack(Rectangle::sized(0, 0), &[Rectangle::square(1)]);
+        assert_eq!(empty.utilization(), 0.0);
+        assert_eq!(empty.render(), "");
+    }
+
+    // 靠近 i32::MAX 的容器：切点超出 i32 时不会溢出 panic，
+    // 只有 x = i32::MAX - 1 和 i32::MAX 两列可以作为起点，小方块都放在这两列里
+    #[test]
+    fn container_at_the_edge_of_i32() {
+        let edge = Rectangle::new(i32::MAX - 1, 0, 10, 10);
+        let small = [Rectangle::square(5), Rectangle::square(1), Rectangle::square(1), Rectangle::square(1)];
+        for packing in both(edge, &small) {
+            assert!(packing.is_valid(&small));
+            assert!(packing.placed.iter().all(|p| p.rect.x >= i32::MAX - 1));
+            assert!(packing.unplaced.is_empty());
+        }
+    }
+
+    // 宽度超过 i32::MAX 的容器：第二块板材的起点是 x = 3_000_000_000，无法表示
+    #[test]
+    fn origins_beyond_i32_are_skipped() {
+        let wide = Rectangle::sized(u32::MAX, 1);
+        let planks = [Rectangle::sized(3_000_000_000, 1), Rectangle::sized(3_000_000_000 / 2, 1)];
+        for packing in both(wide, &planks) {
+            assert!(packing.is_valid(&planks));
+            assert_eq!(packing.unplaced, vec![1]);
+        }
+    }
+
+    // 性质测试：一批伪随机的输入，两种算法的结果都必须合法
+    #[test]
+    fn random_batches_are_packed_validly() {
+        let container = Rectangle::sized(20, 10);
+        let mut seed: u32 = 12345;
+        let mut next = |limit: u32| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (seed >> 16) % limit
+        };
+        for _ in 0..200 {
+            let count = next(12) as usize;
+            let batch: Vec<Rectangle> = (0..count).map(|_| Rectangle::sized(next(12), next(12))).collect();
+            for packing in both(container, &batch) {
+                assert!(packing.is_valid(&batch));
+                assert!(packing.utilization() <= 1.0);
+            }
+        }
+    }
+}
@@ -106,11 +106,32 @@ impl Rectangle {
     }
 
     fn can_hold(&self, other: &Rectangle) -> bool {
-        self.width > other.width && self.height > other.height
+        self.width >= other.width && self.height >= other.height
     }
 
     // 关联函数
     fn square(size: u32) -> Rectangle {
         Rectangle { width: size, height: size }
     }
-}
\ No newline at end of file
+}
+
+// 课程注册，见 lesson.rs
+pub struct StructsLesson;
+
+impl crate::lesson::Lesson for StructsLesson {
+    fn title(&self) -> &'static str {
+        "Structs"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("structs.rs")
+    }
+
+    fn run(&self) {
+        structs_example()
+    }
+}
@@ -0,0 +1,456 @@
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+// 用户账户（User Accounts）
+// `structs.rs` 中的 `User` 字段都是公开的：任何字符串都能当作邮箱，
+// `build_user` 也总是把 `sign_in_count` 设成 1。
+// 这里把字段设为私有，只能通过构建器（Builder）创建用户，
+// 构建时校验用户名和邮箱，出错时返回带类型的错误，而不是 panic 或者 bool。
+// 登录次数不再是一个可以随意修改的数字，而是由登录时间记录推算出来的。
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct User {
+    active: bool,
+    username: String,
+    email: String,
+    // 每次登录的时间，按时间顺序排列
+    sign_ins: Vec<SystemTime>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserError {
+    MissingUsername,
+    MissingEmail,
+    // 用户名长度（按字符计）超出 3..=20
+    UsernameLength(usize),
+    UsernameStart(char),
+    UsernameChar(char),
+    EmailMissingAt,
+    EmailEmptyLocalPart,
+    EmailInvalidDomain(String),
+    EmailChar(char),
+    Inactive,
+    // 登录时间早于上一次登录
+    SignInOutOfOrder,
+}
+
+impl fmt::Display for UserError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UserError::MissingUsername => write!(f, "username is required"),
+            UserError::MissingEmail => write!(f, "email is required"),
+            UserError::UsernameLength(len) => {
+                write!(f, "username must be {}-{} characters, got {}", USERNAME_MIN, USERNAME_MAX, len)
+            }
+            UserError::UsernameStart(c) => write!(f, "username must start with a letter, not {:?}", c),
+            UserError::UsernameChar(c) => write!(f, "username cannot contain {:?}", c),
+            UserError::EmailMissingAt => write!(f, "email must contain exactly one '@'"),
+            UserError::EmailEmptyLocalPart => write!(f, "email has nothing before '@'"),
+            UserError::EmailInvalidDomain(domain) => write!(f, "invalid email domain: {:?}", domain),
+            UserError::EmailChar(c) => write!(f, "email cannot contain {:?}", c),
+            UserError::Inactive => write!(f, "account is inactive"),
+            UserError::SignInOutOfOrder => write!(f, "sign-in time is earlier than the last sign-in"),
+        }
+    }
+}
+
+impl std::error::Error for UserError {}
+
+const USERNAME_MIN: usize = 3;
+const USERNAME_MAX: usize = 20;
+
+// 用户名：3 到 20 个字符，以字母开头，只能包含 ASCII 字母、数字、`_`、`-`、`.`
+pub fn validate_username(username: &str) -> Result<(), UserError> {
+    let len = username.chars().count();
+    if !(USERNAME_MIN..=USERNAME_MAX).contains(&len) {
+        return Err(UserError::UsernameLength(len));
+    }
+    let first = username.chars().next().unwrap_or_default();
+    if !first.is_ascii_alphabetic() {
+        return Err(UserError::UsernameStart(first));
+    }
+    match username.chars().find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))) {
+        Some(c) => Err(UserError::UsernameChar(c)),
+        None => Ok(()),
+    }
+}
+
+// 邮箱：这里只做最基本的检查，完整的 RFC 5322 语法复杂得多。
+// - 恰好一个 `@`，前面不为空
+// - 域名至少有两段，用 `.` 分隔，每段非空，只含字母、数字和 `-`，且不以 `-` 开头或结尾
+// - 不含空白和控制字符
+pub fn validate_email(email: &str) -> Result<(), UserError> {
+    if let Some(c) = email.chars().find(|c| c.is_whitespace() || c.is_control()) {
+        return Err(UserError::EmailChar(c));
+    }
+    let Some((local, domain)) = email.split_once('@') else {
+        return Err(UserError::EmailMissingAt);
+    };
+    if domain.contains('@') {
+        return Err(UserError::EmailMissingAt);
+    }
+    if local.is_empty() {
+        return Err(UserError::EmailEmptyLocalPart);
+    }
+
+    let labels: Vec<&str> = domain.split('.').collect();
+    let valid_label = |label: &&str| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+    if labels.len() < 2 || !labels.iter().all(valid_label) {
+        return Err(UserError::EmailInvalidDomain(domain.to_string()));
+    }
+    Ok(())
+}
+
+// 域名不区分大小写，统一存成小写；`@` 前面的部分原样保留
+fn normalize_email(email: &str) -> String {
+    match email.split_once('@') {
+        Some((local, domain)) => format!("{}@{}", local, domain.to_ascii_lowercase()),
+        None => email.to_string(),
+    }
+}
+
+// 构建器（Builder）
+// 必填字段用 Option 保存，`build` 时才检查是否齐全、是否合法。
+// 每个设置方法都获取 `self` 的所有权并返回它，所以可以链式调用。
+#[derive(Debug, Default)]
+pub struct UserBuilder {
+    username: Option<String>,
+    email: Option<String>,
+    active: Option<bool>,
+}
+
+impl UserBuilder {
+    pub fn username(mut self, username: &str) -> UserBuilder {
+        self.username = Some(username.to_string());
+        self
+    }
+
+    pub fn email(mut self, email: &str) -> UserBuilder {
+        self.email = Some(email.to_string());
+        self
+    }
+
+    pub fn active(mut self, active: bool) -> UserBuilder {
+        self.active = Some(active);
+        self
+    }
+
+    pub fn build(self) -> Result<User, UserError> {
+        let username = self.username.ok_or(UserError::MissingUsername)?;
+        let email = self.email.ok_or(UserError::MissingEmail)?;
+        validate_username(&username)?;
+        validate_email(&email)?;
+
+        Ok(User {
+            active: self.active.unwrap_or(true),
+            username,
+            email: normalize_email(&email),
+            // 新用户还没有登录过
+            sign_ins: Vec::new(),
+        })
+    }
+}
+
+impl User {
+    pub fn builder() -> UserBuilder {
+        UserBuilder::default()
+    }
+
+    // 字段是私有的，外部只能通过这些方法读取
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    // 修改邮箱也要经过校验，失败时保持原来的邮箱不变
+    pub fn set_email(&mut self, email: &str) -> Result<(), UserError> {
+        validate_email(email)?;
+        self.email = normalize_email(email);
+        Ok(())
+    }
+
+    pub fn activate(&mut self) {
+        self.active = true;
+    }
+
+    pub fn deactivate(&mut self) {
+        self.active = false;
+    }
+
+    // 记录一次登录，返回登录后的总次数
+    pub fn sign_in(&mut self) -> Result<u64, UserError> {
+        self.sign_in_at(SystemTime::now())
+    }
+
+    // 使用指定的时间登录，方便重放历史记录
+    pub fn sign_in_at(&mut self, time: SystemTime) -> Result<u64, UserError> {
+        if !self.active {
+            return Err(UserError::Inactive);
+        }
+        if self.last_sign_in().is_some_and(|last| time < last) {
+            return Err(UserError::SignInOutOfOrder);
+        }
+        self.sign_ins.push(time);
+        Ok(self.sign_in_count())
+    }
+
+    pub fn sign_in_count(&self) -> u64 {
+        self.sign_ins.len() as u64
+    }
+
+    pub fn last_sign_in(&self) -> Option<SystemTime> {
+        self.sign_ins.last().copied()
+    }
+
+    pub fn sign_ins(&self) -> &[SystemTime] {
+        &self.sign_ins
+    }
+
+    // 在 `now` 之前的 `window` 时间段内登录了几次
+    pub fn sign_ins_within(&self, window: Duration, now: SystemTime) -> usize {
+        self.sign_ins
+            .iter()
+            .filter(|&&time| now.duration_since(time).is_ok_and(|elapsed| elapsed <= window))
+            .count()
+    }
+}
+
+impl fmt::Display for User {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let status = if self.active { "active" } else { "inactive" };
+        write!(f, "{} <{}> ({}, {} sign-ins)", self.username, self.email, status, self.sign_ins.len())
+    }
+}
+
+pub fn user_example() {
+    // 使用构建器创建用户
+    let mut user1 = User::builder()
+        .username("someusername123")
+        .email("someone@Example.COM")
+        .build()
+        .unwrap();
+    println!("User 1: {}", user1);
+
+    // 校验失败时返回具体的错误
+    for (username, email) in [("al", "a@example.com"), ("alice", "alice@localhost"), ("alice", "alice @example.com")] {
+        match User::builder().username(username).email(email).build() {
+            Ok(user) => println!("created {}", user),
+            Err(error) => println!("{:?} / {:?}: {}", username, email, error),
+        }
+    }
+
+    // 修改邮箱：不合法的邮箱被拒绝，原来的值保持不变
+    if let Err(error) = user1.set_email("not an email") {
+        println!("rejected new email: {}, still {}", error, user1.email());
+    }
+    user1.set_email("anotheremail@example.com").unwrap();
+
+    // 登录记录
+    let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let hour = Duration::from_secs(3600);
+    for offset in [0, 1, 5, 2] {
+        match user1.sign_in_at(start + offset * hour) {
+            Ok(count) => println!("sign-in #{} at +{}h", count, offset),
+            Err(error) => println!("sign-in at +{}h rejected: {}", offset, error),
+        }
+    }
+    println!("sign-ins within 4h before the last one: {}", user1.sign_ins_within(4 * hour, start + 5 * hour));
+
+    // 停用的账户不能登录，登录次数不变
+    user1.deactivate();
+    println!("inactive sign-in: {:?}", user1.sign_in());
+    user1.activate();
+    println!("active again, sign-in: {:?}", user1.sign_in());
+    println!("User 1: {}", user1);
+
+    // 结构体更新语法（Struct Update Syntax）
+    // 字段是私有的，所以只有这个模块内部才能写结构体字面量；外部代码必须经过构建器。
+    // `..user1` 会把剩下的字段逐个 **移动** 或 **复制** 过来：
+    // `active` 是 bool，实现了 Copy，只是复制；
+    // `username`（String）和 `sign_ins`（Vec）没有实现 Copy，它们的所有权被移动到了 user2。
+    let user2 = User {
+        email: String::from("user2@example.com"),
+        ..user1
+    };
+    println!("User 2: {}", user2);
+
+    // user1 现在被“部分移动”了：没有被移动的字段仍然可以单独使用
+    println!("user1.email = {}, user1.active = {}", user1.email, user1.active);
+    // println!("{}", user1.username); // 错误！username 的所有权已经移动到了 user2
+    // println!("{}", user1.email()); // 错误！方法接收 &self，需要借用整个 user1，而它已经被部分移动
+    // let user4 = user1; // 错误！不能移动一个被部分移动的值
+
+    // 给被移动的字段重新赋值后，user1 又是完整的了
+    user1.username = String::from("renamed");
+    user1.sign_ins = Vec::new();
+
+    // 如果不想移动，可以从克隆体更新：user1 保持完整
+    let user3 = User {
+        email: String::from("user3@example.com"),
+        ..user1.clone()
+    };
+    println!("User 1: {}, User 3: {}", user1, user3);
+}
+
+// 课程注册，见 lesson.rs
+pub struct UserLesson;
+
+impl crate::lesson::Lesson for UserLesson {
+    fn title(&self) -> &'static str {
+        "User Accounts"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("user.rs")
+    }
+
+    fn run(&self) {
+        user_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alice() -> User {
+        User::builder().username("someusername123").email("someone@Example.COM").build().unwrap()
+    }
+
+    #[test]
+    fn builder_normalizes_email() {
+        let user = alice();
+        assert_eq!(user.username(), "someusername123");
+        assert_eq!(user.email(), "someone@example.com");
+        assert!(user.is_active());
+        assert_eq!(user.sign_in_count(), 0);
+        assert_eq!(user.last_sign_in(), None);
+    }
+
+    #[test]
+    fn validation_errors() {
+        let cases = [
+            (None, Some("a@example.com"), UserError::MissingUsername),
+            (Some("alice"), None, UserError::MissingEmail),
+            (Some("al"), Some("a@example.com"), UserError::UsernameLength(2)),
+            (Some("1alice"), Some("a@example.com"), UserError::UsernameStart('1')),
+            (Some("alice bob"), Some("a@example.com"), UserError::UsernameChar(' ')),
+            (Some("alice"), Some("alice.example.com"), UserError::EmailMissingAt),
+            (Some("alice"), Some("a@b@example.com"), UserError::EmailMissingAt),
+            (Some("alice"), Some("@example.com"), UserError::EmailEmptyLocalPart),
+            (Some("alice"), Some("alice@localhost"), UserError::EmailInvalidDomain("localhost".to_string())),
+            (Some("alice"), Some("alice@-bad.com"), UserError::EmailInvalidDomain("-bad.com".to_string())),
+            (Some("alice"), Some("alice@example..com"), UserError::EmailInvalidDomain("example..com".to_string())),
+            (Some("alice"), Some("alice @example.com"), UserError::EmailChar(' ')),
+        ];
+        for (username, email, expected) in cases {
+            let mut builder = User::builder();
+            if let Some(username) = username {
+                builder = builder.username(username);
+            }
+            if let Some(email) = email {
+                builder = builder.email(email);
+            }
+            assert_eq!(builder.build().unwrap_err(), expected, "{:?} / {:?}", username, email);
+        }
+    }
+
+    // 用户名长度按字符计，而不是按字节
+    #[test]
+    fn username_length_counts_chars() {
+        assert_eq!(validate_username(&"a".repeat(21)), Err(UserError::UsernameLength(21)));
+        assert_eq!(validate_username("zoë"), Err(UserError::UsernameChar('ë')));
+        assert!(validate_username("first.last-2_x").is_ok());
+    }
+
+    #[test]
+    fn rejected_email_keeps_old_value() {
+        let mut user = alice();
+        assert_eq!(user.set_email("not an email"), Err(UserError::EmailChar(' ')));
+        assert_eq!(user.email(), "someone@example.com");
+        user.set_email("anotheremail@example.com").unwrap();
+        assert_eq!(user.email(), "anotheremail@example.com");
+    }
+
+    #[test]
+    fn sign_ins_are_recorded_in_order() {
+        let mut user = alice();
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let hour = Duration::from_secs(3600);
+        assert_eq!(user.sign_in_at(start), Ok(1));
+        assert_eq!(user.sign_in_at(start + hour), Ok(2));
+        assert_eq!(user.sign_in_at(start + 5 * hour), Ok(3));
+        assert_eq!(user.sign_in_at(start + 2 * hour), Err(UserError::SignInOutOfOrder));
+        assert_eq!(user.last_sign_in(), Some(start + 5 * hour));
+        assert_eq!(user.sign_ins_within(4 * hour, start + 5 * hour), 2);
+        assert_eq!(user.sign_ins(), &[start, start + hour, start + 5 * hour]);
+    }
+
+    // 停用的账户不能登录，登录次数不变
+    #[test]
+    fn inactive_accounts_cannot_sign_in() {
+        let mut user = alice();
+        user.sign_in().unwrap();
+        user.deactivate();
+        assert_eq!(user.sign_in(), Err(UserError::Inactive));
+        assert_eq!(user.sign_in_count(), 1);
+        user.activate();
+        assert_eq!(user.sign_in(), Ok(2));
+
+        let mut pending = User::builder().username("pending").email("p@example.org").active(false).build().unwrap();
+        assert_eq!(pending.sign_in(), Err(UserError::Inactive));
+        pending.activate();
+        assert_eq!(pending.sign_in(), Ok(1));
+    }
+
+    // `..user1` 移动了 username 和 sign_ins，复制了 active
+    #[test]
+    fn struct_update_partially_moves() {
+        let mut user1 = alice();
+        user1.set_email("anotheremail@example.com").unwrap();
+        user1.sign_in().unwrap();
+        let user2 = User {
+            email: String::from("user2@example.com"),
+            ..user1
+        };
+        assert_eq!(user2.username, "someusername123");
+        assert_eq!(user2.sign_in_count(), 1);
+
+        // 没有被移动的字段仍然可以单独使用
+        assert_eq!(user1.email, "anotheremail@example.com");
+        assert!(user1.active);
+
+        // 给被移动的字段重新赋值后，user1 又是完整的了
+        user1.username = String::from("renamed");
+        user1.sign_ins = Vec::new();
+        assert_eq!(user1.username(), "renamed");
+        assert_eq!(user1.sign_in_count(), 0);
+    }
+
+    #[test]
+    fn struct_update_from_clone_keeps_original() {
+        let user1 = alice();
+        let user3 = User {
+            email: String::from("user3@example.com"),
+            ..user1.clone()
+        };
+        assert_eq!(user3.username(), user1.username());
+        assert_ne!(user3, user1);
+    }
+}
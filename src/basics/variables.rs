@@ -101,3 +101,24 @@ pub fn variables_example() {
     // 正确！`_mutable_integer` 在这里没有被冻结
     _mutable_integer = 3;
 }
+
+// 课程注册，见 lesson.rs
+pub struct VariablesLesson;
+
+impl crate::lesson::Lesson for VariablesLesson {
+    fn title(&self) -> &'static str {
+        "Variables & Mutability"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("variables.rs")
+    }
+
+    fn run(&self) {
+        variables_example()
+    }
+}
@@ -0,0 +1,622 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+// 自动售货机与找零（Vending Machine）
+// `enums.rs` 中的 `Coin` 只有四种硬币和两个州。这里补全全部 50 个州，
+// 再用它们搭一台售货机：投币、累计余额、选择商品、找零、退币。
+// 找零有两种策略：
+// - 贪心（Greedy）：每次拿面值最大的、还放得下的硬币。硬币无限时，美国硬币的面值体系下贪心总是最优的；
+//   但售货机里每种硬币的数量有限，贪心可能明明凑得出却失败。
+// - 动态规划（Dynamic Programming）：对每个金额记录最少需要几枚硬币，一定能找到最优解。
+
+// 用宏同时生成枚举、全部变体的列表和显示名称，避免三处手写 50 个州时漏掉或写错
+macro_rules! us_states {
+    ($($state:ident => $name:literal),* $(,)?) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum UsState {
+            $($state),*
+        }
+
+        impl UsState {
+            pub const ALL: &'static [UsState] = &[$(UsState::$state),*];
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(UsState::$state => $name),*
+                }
+            }
+        }
+    };
+}
+
+us_states! {
+    Alabama => "Alabama", Alaska => "Alaska", Arizona => "Arizona", Arkansas => "Arkansas",
+    California => "California", Colorado => "Colorado", Connecticut => "Connecticut",
+    Delaware => "Delaware", Florida => "Florida", Georgia => "Georgia", Hawaii => "Hawaii",
+    Idaho => "Idaho", Illinois => "Illinois", Indiana => "Indiana", Iowa => "Iowa",
+    Kansas => "Kansas", Kentucky => "Kentucky", Louisiana => "Louisiana", Maine => "Maine",
+    Maryland => "Maryland", Massachusetts => "Massachusetts", Michigan => "Michigan",
+    Minnesota => "Minnesota", Mississippi => "Mississippi", Missouri => "Missouri",
+    Montana => "Montana", Nebraska => "Nebraska", Nevada => "Nevada",
+    NewHampshire => "New Hampshire", NewJersey => "New Jersey", NewMexico => "New Mexico",
+    NewYork => "New York", NorthCarolina => "North Carolina", NorthDakota => "North Dakota",
+    Ohio => "Ohio", Oklahoma => "Oklahoma", Oregon => "Oregon", Pennsylvania => "Pennsylvania",
+    RhodeIsland => "Rhode Island", SouthCarolina => "South Carolina", SouthDakota => "South Dakota",
+    Tennessee => "Tennessee", Texas => "Texas", Utah => "Utah", Vermont => "Vermont",
+    Virginia => "Virginia", Washington => "Washington", WestVirginia => "West Virginia",
+    Wisconsin => "Wisconsin", Wyoming => "Wyoming",
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Coin {
+    Penny,
+    Nickel,
+    Dime,
+    Quarter(UsState),
+}
+
+impl Coin {
+    pub fn value_in_cents(&self) -> u32 {
+        match self {
+            Coin::Penny => 1,
+            Coin::Nickel => 5,
+            Coin::Dime => 10,
+            Coin::Quarter(_) => 25,
+        }
+    }
+}
+
+// 金额统一用美分表示，显示时写成 $1.25
+fn dollars(cents: u32) -> String {
+    format!("${}.{:02}", cents / 100, cents % 100)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeStrategy {
+    Greedy,
+    Optimal,
+}
+
+// 找零：`coins` 是 (面值, 可用数量)，按面值从大到小排列。
+// 返回每种面值各用几枚，凑不出来时返回 None
+pub fn make_change(amount: u32, coins: &[(u32, usize)], strategy: ChangeStrategy) -> Option<Vec<usize>> {
+    match strategy {
+        ChangeStrategy::Greedy => greedy_change(amount, coins),
+        ChangeStrategy::Optimal => optimal_change(amount, coins),
+    }
+}
+
+fn greedy_change(amount: u32, coins: &[(u32, usize)]) -> Option<Vec<usize>> {
+    let mut remaining = amount;
+    let mut used = Vec::with_capacity(coins.len());
+    for &(value, available) in coins {
+        // 面值为 0 的硬币凑不出任何金额，和 optimal_change 一样跳过（也避免除以 0）
+        if value == 0 {
+            used.push(0);
+            continue;
+        }
+        let take = (remaining / value).min(available as u32);
+        remaining -= take * value;
+        used.push(take as usize);
+    }
+    if remaining == 0 { Some(used) } else { None }
+}
+
+// 有限数量的硬币找零：有界背包（bounded knapsack），一种面值一种面值地加进来。
+// `best[a]` 是目前为止凑出金额 a 最少需要几枚硬币，`taken[kind][a]` 记录这个最优方案里
+// 第 kind 种面值用了几枚（回溯指针），最后从金额 amount 倒着走一遍就能还原出每种面值的数量。
+//
+// 加入面值 v（最多 k 枚）时，金额 a 只会从 a - v、a - 2v、……、a - kv 转移过来，
+// 所以按 a % v 分组，每组内是一个长度为 k + 1 的滑动窗口求最小值，用单调队列做到每个金额 O(1)。
+// 总复杂度 O(金额 × 面值种数)，和每种硬币有多少枚无关
+fn optimal_change(amount: u32, coins: &[(u32, usize)]) -> Option<Vec<usize>> {
+    let amount = amount as usize;
+    let mut best: Vec<Option<usize>> = vec![None; amount + 1];
+    best[0] = Some(0);
+    let mut taken: Vec<Vec<usize>> = Vec::with_capacity(coins.len());
+
+    for &(value, available) in coins {
+        let value = value as usize;
+        let mut counts = vec![0; amount + 1];
+        // 面值为 0 或者一枚都没有的硬币用不上
+        if value == 0 || available == 0 {
+            taken.push(counts);
+            continue;
+        }
+        let previous = best.clone();
+        for residue in 0..value.min(amount + 1) {
+            // 组内第 j 个金额是 residue + j * v。从第 i 个金额再加 (j - i) 枚这种硬币，
+            // 总数是 previous[i] + (j - i)，所以窗口里按 previous[i] - i 比较
+            let mut window: VecDeque<(usize, isize)> = VecDeque::new();
+            for (j, a) in (residue..=amount).step_by(value).enumerate() {
+                if let Some(count) = previous[a] {
+                    let key = count as isize - j as isize;
+                    while window.back().is_some_and(|&(_, back)| back >= key) {
+                        window.pop_back();
+                    }
+                    window.push_back((j, key));
+                }
+                // 最多用 available 枚，更早的起点已经用不上了
+                while window.front().is_some_and(|&(i, _)| j - i > available) {
+                    window.pop_front();
+                }
+                best[a] = window.front().map(|&(i, key)| {
+                    counts[a] = j - i;
+                    (key + j as isize) as usize
+                });
+            }
+        }
+        taken.push(counts);
+    }
+
+    best[amount]?;
+    let mut remaining = amount;
+    let mut used = vec![0; coins.len()];
+    for (kind, &(value, _)) in coins.iter().enumerate().rev() {
+        used[kind] = taken[kind][remaining];
+        remaining -= used[kind] * value as usize;
+    }
+    Some(used)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Product {
+    pub name: &'static str,
+    pub price: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VendError {
+    UnknownSlot(usize),
+    SoldOut(&'static str),
+    InsufficientFunds { price: u32, balance: u32 },
+    // 钱箱里的硬币凑不出找零，这次交易被拒绝，余额保持不变
+    CannotMakeChange(u32),
+}
+
+impl fmt::Display for VendError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VendError::UnknownSlot(slot) => write!(f, "there is no slot {}", slot),
+            VendError::SoldOut(name) => write!(f, "{} is sold out", name),
+            VendError::InsufficientFunds { price, balance } => {
+                write!(f, "price is {}, balance is only {}", dollars(*price), dollars(*balance))
+            }
+            VendError::CannotMakeChange(amount) => write!(f, "cannot make change for {}", dollars(*amount)),
+        }
+    }
+}
+
+impl std::error::Error for VendError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vend {
+    pub product: Product,
+    pub change: Vec<Coin>,
+}
+
+// 钱箱：一分、五分、一角硬币只需要计数，25 美分硬币要记住是哪个州的
+#[derive(Debug, Clone, Default)]
+struct CashBox {
+    pennies: usize,
+    nickels: usize,
+    dimes: usize,
+    quarters: Vec<UsState>,
+}
+
+impl CashBox {
+    fn add(&mut self, coin: Coin) {
+        match coin {
+            Coin::Penny => self.pennies += 1,
+            Coin::Nickel => self.nickels += 1,
+            Coin::Dime => self.dimes += 1,
+            Coin::Quarter(state) => self.quarters.push(state),
+        }
+    }
+
+    fn total(&self) -> u32 {
+        (self.pennies + 5 * self.nickels + 10 * self.dimes + 25 * self.quarters.len()) as u32
+    }
+
+    // 从钱箱里取出找零用的硬币；先检查能不能凑出来，凑不出来时钱箱保持不变
+    fn take_change(&mut self, amount: u32, strategy: ChangeStrategy) -> Option<Vec<Coin>> {
+        let available = [(25, self.quarters.len()), (10, self.dimes), (5, self.nickels), (1, self.pennies)];
+        let [quarters, dimes, nickels, pennies] = make_change(amount, &available, strategy)?[..] else {
+            unreachable!("one count per denomination");
+        };
+
+        self.dimes -= dimes;
+        self.nickels -= nickels;
+        self.pennies -= pennies;
+        // 后投进来的 25 美分硬币先找出去
+        let mut change: Vec<Coin> = self
+            .quarters
+            .split_off(self.quarters.len() - quarters)
+            .into_iter()
+            .rev()
+            .map(Coin::Quarter)
+            .collect();
+        change.extend(std::iter::repeat_n(Coin::Dime, dimes));
+        change.extend(std::iter::repeat_n(Coin::Nickel, nickels));
+        change.extend(std::iter::repeat_n(Coin::Penny, pennies));
+        Some(change)
+    }
+}
+
+pub struct VendingMachine {
+    slots: Vec<(Product, u32)>,
+    balance: u32,
+    cash: CashBox,
+    strategy: ChangeStrategy,
+    // 每个州的 25 美分硬币一共收到了多少枚（找零出去的也算在内）
+    collected: HashMap<UsState, u32>,
+}
+
+impl VendingMachine {
+    pub fn new(strategy: ChangeStrategy) -> VendingMachine {
+        VendingMachine {
+            slots: Vec::new(),
+            balance: 0,
+            cash: CashBox::default(),
+            strategy,
+            collected: HashMap::new(),
+        }
+    }
+
+    // 上货，返回货道编号
+    pub fn stock(&mut self, product: Product, count: u32) -> usize {
+        self.slots.push((product, count));
+        self.slots.len() - 1
+    }
+
+    // 预先放进钱箱的零钱，不计入余额
+    pub fn load_change(&mut self, coins: impl IntoIterator<Item = Coin>) {
+        for coin in coins {
+            self.cash.add(coin);
+        }
+    }
+
+    pub fn balance(&self) -> u32 {
+        self.balance
+    }
+
+    pub fn cash_total(&self) -> u32 {
+        self.cash.total()
+    }
+
+    pub fn insert(&mut self, coin: Coin) -> u32 {
+        if let Coin::Quarter(state) = coin {
+            *self.collected.entry(state).or_insert(0) += 1;
+        }
+        self.cash.add(coin);
+        self.balance += coin.value_in_cents();
+        self.balance
+    }
+
+    // 投入一串硬币，返回最终余额
+    pub fn insert_all(&mut self, coins: impl IntoIterator<Item = Coin>) -> u32 {
+        for coin in coins {
+            self.insert(coin);
+        }
+        self.balance
+    }
+
+    pub fn select(&mut self, slot: usize) -> Result<Vend, VendError> {
+        let &(product, count) = self.slots.get(slot).ok_or(VendError::UnknownSlot(slot))?;
+        if count == 0 {
+            return Err(VendError::SoldOut(product.name));
+        }
+        if self.balance < product.price {
+            return Err(VendError::InsufficientFunds { price: product.price, balance: self.balance });
+        }
+
+        let owed = self.balance - product.price;
+        let change = self
+            .cash
+            .take_change(owed, self.strategy)
+            .ok_or(VendError::CannotMakeChange(owed))?;
+        self.slots[slot].1 -= 1;
+        self.balance = 0;
+        Ok(Vend { product, change })
+    }
+
+    // 退回全部余额
+    pub fn refund(&mut self) -> Result<Vec<Coin>, VendError> {
+        let change = self
+            .cash
+            .take_change(self.balance, self.strategy)
+            .ok_or(VendError::CannotMakeChange(self.balance))?;
+        self.balance = 0;
+        Ok(change)
+    }
+
+    // 所有 50 个州各收到了几枚 25 美分硬币，按州名的顺序排列
+    pub fn quarter_report(&self) -> Vec<(UsState, u32)> {
+        UsState::ALL
+            .iter()
+            .map(|state| (*state, self.collected.get(state).copied().unwrap_or(0)))
+            .collect()
+    }
+
+    pub fn render_quarter_report(&self) -> String {
+        let report = self.quarter_report();
+        let mut out = String::new();
+        for (state, count) in report.iter().filter(|(_, count)| *count > 0) {
+            out.push_str(&format!("{:<15} {}\n", state.name(), count));
+        }
+        let missing: Vec<&str> = report
+            .iter()
+            .filter(|(_, count)| *count == 0)
+            .map(|(state, _)| state.name())
+            .collect();
+        out.push_str(&format!(
+            "{}/{} states collected; missing: {}\n",
+            UsState::ALL.len() - missing.len(),
+            UsState::ALL.len(),
+            if missing.is_empty() { String::from("none") } else { missing.join(", ") }
+        ));
+        out
+    }
+}
+
+pub fn vending_example() {
+    println!("{} states, Alaska quarter = {} cents", UsState::ALL.len(), Coin::Quarter(UsState::Alaska).value_in_cents());
+
+    // 无限硬币时美国硬币的贪心找零是最优的，但换一套面值就不一定了
+    let odd = [(4, 10), (3, 10), (1, 10)];
+    for strategy in [ChangeStrategy::Greedy, ChangeStrategy::Optimal] {
+        println!("{:?} change for 6 with [4, 3, 1]: {:?}", strategy, make_change(6, &odd, strategy));
+    }
+    // 有界背包的耗时和硬币的数量无关：一万枚一分硬币找 100 美元也很快
+    let pennies = [(25, 3), (10, 2), (5, 1), (1, 10_000)];
+    println!("$100 from mostly pennies: {:?}", make_change(10_000, &pennies, ChangeStrategy::Optimal));
+
+    // 售货机
+    let mut machine = VendingMachine::new(ChangeStrategy::Optimal);
+    let chips = machine.stock(Product { name: "Chips", price: 65 }, 2);
+    let gum = machine.stock(Product { name: "Gum", price: 35 }, 1);
+    machine.load_change([Coin::Dime, Coin::Dime, Coin::Nickel, Coin::Penny, Coin::Penny]);
+
+    machine.insert_all([
+        Coin::Quarter(UsState::Alaska),
+        Coin::Quarter(UsState::Texas),
+        Coin::Quarter(UsState::Alaska),
+    ]);
+    let vend = machine.select(chips).unwrap();
+    println!("Bought {} for {}, change: {:?}", vend.product.name, dollars(vend.product.price), vend.change);
+
+    // 各种失败：余额不足、不存在的货道、卖光了
+    machine.insert(Coin::Dime);
+    println!("{}", machine.select(gum).unwrap_err());
+    println!("{}", machine.select(9).unwrap_err());
+    machine.insert(Coin::Quarter(UsState::Ohio));
+    println!("Gum change: {:?}", machine.select(gum).map(|vend| vend.change));
+    machine.insert(Coin::Quarter(UsState::Maine));
+    println!("{}", machine.select(gum).unwrap_err());
+
+    // 退币：余额原样退回（以最少的硬币数）
+    println!("refund: {:?}", machine.refund());
+
+    // 硬币有限时贪心会失败：要找 30 美分，钱箱里有 1 枚 25 美分和 3 枚一角，没有五分和一分
+    for strategy in [ChangeStrategy::Greedy, ChangeStrategy::Optimal] {
+        let mut machine = VendingMachine::new(strategy);
+        let soda = machine.stock(Product { name: "Soda", price: 70 }, 5);
+        machine.load_change([Coin::Quarter(UsState::Iowa), Coin::Dime, Coin::Dime, Coin::Dime]);
+        machine.insert_all([Coin::Quarter(UsState::Utah); 4]);
+        println!("{:?}: {:?}, balance {}", strategy, machine.select(soda).map(|vend| vend.change), dollars(machine.balance()));
+    }
+
+    // 各州 25 美分硬币的收集情况
+    print!("{}", machine.render_quarter_report());
+}
+
+// 课程注册，见 lesson.rs
+pub struct VendingLesson;
+
+impl crate::lesson::Lesson for VendingLesson {
+    fn title(&self) -> &'static str {
+        "Vending Machine"
+    }
+
+    fn path(&self) -> &'static str {
+        file!()
+    }
+
+    fn source(&self) -> &'static str {
+        include_str!("vending.rs")
+    }
+
+    fn run(&self) {
+        vending_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn total(coins: &[Coin]) -> u32 {
+        coins.iter().map(Coin::value_in_cents).sum()
+    }
+
+    // 线性同余伪随机数，固定种子，每次运行结果都一样
+    fn lcg(mut seed: u32) -> impl FnMut(u32) -> u32 {
+        move |limit| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (seed >> 16) % limit
+        }
+    }
+
+    #[test]
+    fn states_and_coins() {
+        assert_eq!(UsState::ALL.len(), 50);
+        assert_eq!(UsState::NewHampshire.name(), "New Hampshire");
+        assert_eq!(Coin::Quarter(UsState::Alaska).value_in_cents(), 25);
+        assert_eq!(dollars(125), "$1.25");
+    }
+
+    #[test]
+    fn greedy_is_optimal_for_us_coins_with_plenty_of_each() {
+        let plenty = [(25, 12), (10, 30), (5, 60), (1, 300)];
+        for amount in 0..=300 {
+            let greedy = make_change(amount, &plenty, ChangeStrategy::Greedy);
+            assert_eq!(greedy, make_change(amount, &plenty, ChangeStrategy::Optimal), "{}", amount);
+        }
+    }
+
+    #[test]
+    fn greedy_is_not_optimal_for_other_denominations() {
+        let odd = [(4, 10), (3, 10), (1, 10)];
+        assert_eq!(make_change(6, &odd, ChangeStrategy::Greedy), Some(vec![1, 0, 2]));
+        assert_eq!(make_change(6, &odd, ChangeStrategy::Optimal), Some(vec![0, 2, 0]));
+        assert_eq!(make_change(7, &[(5, 1), (2, 0)], ChangeStrategy::Optimal), None);
+    }
+
+    // 有界背包的耗时和硬币的数量无关
+    #[test]
+    fn many_pennies() {
+        let pennies = [(25, 3), (10, 2), (5, 1), (1, 10_000)];
+        assert_eq!(make_change(10_000, &pennies, ChangeStrategy::Optimal), Some(vec![3, 2, 1, 9_900]));
+    }
+
+    // 面值为 0 的硬币两种策略都不会使用
+    #[test]
+    fn zero_value_coins_are_ignored() {
+        let with_zero = [(5, 1), (0, 3), (1, 5)];
+        for strategy in [ChangeStrategy::Greedy, ChangeStrategy::Optimal] {
+            assert_eq!(make_change(7, &with_zero, strategy), Some(vec![1, 0, 2]));
+            assert_eq!(make_change(0, &with_zero, strategy), Some(vec![0, 0, 0]));
+            assert_eq!(make_change(3, &[(0, 9)], strategy), None);
+        }
+    }
+
+    #[test]
+    fn buying_failing_and_refunding() {
+        let mut machine = VendingMachine::new(ChangeStrategy::Optimal);
+        let chips = machine.stock(Product { name: "Chips", price: 65 }, 2);
+        let gum = machine.stock(Product { name: "Gum", price: 35 }, 1);
+        machine.load_change([Coin::Dime, Coin::Dime, Coin::Nickel, Coin::Penny, Coin::Penny]);
+
+        let coins = [
+            Coin::Quarter(UsState::Alaska),
+            Coin::Quarter(UsState::Texas),
+            Coin::Quarter(UsState::Alaska),
+        ];
+        assert_eq!(machine.insert_all(coins), 75);
+        let vend = machine.select(chips).unwrap();
+        assert_eq!(vend.product.name, "Chips");
+        assert_eq!(vend.change, vec![Coin::Dime]);
+        assert_eq!(machine.balance(), 0);
+
+        machine.insert(Coin::Dime);
+        assert_eq!(machine.select(gum), Err(VendError::InsufficientFunds { price: 35, balance: 10 }));
+        assert_eq!(machine.select(9), Err(VendError::UnknownSlot(9)));
+        machine.insert_all([Coin::Quarter(UsState::Ohio)]);
+        assert_eq!(machine.select(gum).map(|vend| vend.change), Ok(Vec::new()));
+        machine.insert(Coin::Quarter(UsState::Maine));
+        assert_eq!(machine.select(gum), Err(VendError::SoldOut("Gum")));
+
+        assert_eq!(machine.refund(), Ok(vec![Coin::Quarter(UsState::Maine)]));
+        assert_eq!(machine.balance(), 0);
+
+        let report = machine.quarter_report();
+        assert_eq!(report.len(), 50);
+        assert_eq!(report.iter().find(|(state, _)| *state == UsState::Alaska), Some(&(UsState::Alaska, 2)));
+        // 退回去的硬币也算收集过
+        let rendered = machine.render_quarter_report();
+        assert!(rendered.starts_with("Alaska          2\nMaine           1\nOhio            1\nTexas           1\n"));
+        assert!(rendered.contains("4/50 states collected; missing: Alabama, Arizona,"));
+    }
+
+    // 硬币有限时贪心会失败；失败时余额不变，可以继续退币
+    #[test]
+    fn greedy_fails_with_limited_coins() {
+        let cashbox = [Coin::Quarter(UsState::Iowa), Coin::Dime, Coin::Dime, Coin::Dime];
+        for (strategy, expected) in [
+            (ChangeStrategy::Greedy, Err(VendError::CannotMakeChange(30))),
+            (ChangeStrategy::Optimal, Ok(vec![Coin::Dime, Coin::Dime, Coin::Dime])),
+        ] {
+            let mut machine = VendingMachine::new(strategy);
+            let soda = machine.stock(Product { name: "Soda", price: 70 }, 5);
+            machine.load_change(cashbox);
+            machine.insert_all([Coin::Quarter(UsState::Utah); 4]);
+            let result = machine.select(soda).map(|vend| vend.change);
+            assert_eq!(result, expected);
+            if result.is_err() {
+                assert_eq!(machine.balance(), 100);
+                assert_eq!(total(&machine.refund().unwrap()), 100);
+            }
+        }
+    }
+
+    // 性质测试：随机的钱箱和金额。最优解一定不比贪心差，贪心能凑出来时最优解也能，
+    // 硬币数和穷举的结果相同，而且两种策略都不会用超过可用数量的硬币
+    #[test]
+    fn optimal_matches_brute_force() {
+        let mut next = lcg(2024);
+        for _ in 0..300 {
+            let coins = [(25, next(4) as usize), (10, next(6) as usize), (5, next(3) as usize), (1, next(5) as usize)];
+            let amount = next(150);
+            let greedy = make_change(amount, &coins, ChangeStrategy::Greedy);
+            let optimal = make_change(amount, &coins, ChangeStrategy::Optimal);
+            if let Some(greedy) = &greedy {
+                let optimal = optimal.as_ref().expect("optimal finds a solution whenever greedy does");
+                assert!(optimal.iter().sum::<usize>() <= greedy.iter().sum());
+            }
+            // 硬币很少，可以枚举所有组合，和动态规划的结果对照
+            let mut fewest: Option<usize> = None;
+            for q in 0..=coins[0].1 {
+                for d in 0..=coins[1].1 {
+                    for n in 0..=coins[2].1 {
+                        for p in 0..=coins[3].1 {
+                            if (25 * q + 10 * d + 5 * n + p) as u32 == amount {
+                                fewest = Some(fewest.map_or(q + d + n + p, |f| f.min(q + d + n + p)));
+                            }
+                        }
+                    }
+                }
+            }
+            assert_eq!(optimal.as_ref().map(|counts| counts.iter().sum()), fewest);
+            for counts in [greedy, optimal].into_iter().flatten() {
+                let value: u32 = counts.iter().zip(&coins).map(|(n, (v, _))| *n as u32 * v).sum();
+                assert_eq!(value, amount);
+                assert!(counts.iter().zip(&coins).all(|(n, (_, available))| n <= available));
+            }
+        }
+    }
+
+    // 性质测试：钱始终守恒，投入的钱 = 找出去的零钱 + 钱箱里的钱
+    #[test]
+    fn money_is_conserved() {
+        let mut next = lcg(2025);
+        let mut machine = VendingMachine::new(ChangeStrategy::Optimal);
+        let snack = machine.stock(Product { name: "Snack", price: 45 }, 1000);
+        let mut money_in = 0;
+        let mut change_out = 0;
+        let mut sold = 0;
+        for _ in 0..500 {
+            let coin = match next(4) {
+                0 => Coin::Penny,
+                1 => Coin::Nickel,
+                2 => Coin::Dime,
+                _ => Coin::Quarter(UsState::ALL[next(50) as usize]),
+            };
+            money_in += coin.value_in_cents();
+            machine.insert(coin);
+            if next(5) == 0 {
+                match machine.select(snack) {
+                    Ok(vend) => {
+                        sold += vend.product.price;
+                        change_out += total(&vend.change);
+                    }
+                    Err(VendError::InsufficientFunds { .. } | VendError::CannotMakeChange(_)) => {}
+                    Err(error) => panic!("unexpected error: {}", error),
+                }
+            }
+            // 卖出的钱留在钱箱里，只有找零会离开售货机
+            assert_eq!(money_in, change_out + machine.cash_total());
+            assert_eq!(machine.cash_total(), sold + machine.balance());
+        }
+        assert!(sold > 0);
+    }
+}
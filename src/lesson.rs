@@ -0,0 +1,130 @@
+use crate::tools::corpus::{self, ReadmeSection};
+use crate::{advanced, basics};
+
+// 课程注册表（Lesson Registry）
+// 每节课都是一个签名相同的 `xxx_example()` 函数，再加上一份源码和 README 中的一章。
+// `Lesson` 特征把这些信息放在一起，所有课程存放在一个 `Vec<Box<dyn Lesson>>` 里：
+// 运行课程、搜索、速查表和各种导出器都只通过这个特征访问课程，不需要知道具体是哪一节。
+// 这本身就是特征对象一章的例子——每节课都是一个不同的类型，却能放进同一个集合。
+
+pub trait Lesson {
+    // README 中章节标题括号内的英文名，例如 "Ownership"
+    fn title(&self) -> &'static str;
+
+    // 课程文件相对于项目根目录的路径，各课程直接返回 `file!()`
+    fn path(&self) -> &'static str;
+
+    // 源码在编译期通过 `include_str!` 嵌入，源文件一改动就会重新编译
+    fn source(&self) -> &'static str;
+
+    fn run(&self);
+
+    // 模块名，例如 "ownership"，同时也是 `ownership_example` 的前缀。
+    // 直接从路径里取出文件名，课程里就不用再重复写一遍
+    fn id(&self) -> &'static str {
+        let file = self.path().rsplit(['/', '\\']).next().unwrap_or_default();
+        file.strip_suffix(".rs").unwrap_or(file)
+    }
+
+    // "basics" 或 "advanced"，即课程文件所在的目录
+    fn group(&self) -> &'static str {
+        self.path().split(['/', '\\']).rev().nth(1).unwrap_or_default()
+    }
+
+    // README 中对应的章节，以及它下面所有更深层的小节
+    fn sections(&self) -> Vec<ReadmeSection> {
+        corpus::readme_chapter(self.title())
+    }
+}
+
+// 每节课在自己的模块里定义一个单元结构体并实现 `Lesson`，这里只负责按运行顺序列出它们
+pub fn registry() -> Vec<Box<dyn Lesson>> {
+    vec![
+        Box::new(basics::variables::VariablesLesson),
+        Box::new(basics::functions::FunctionsLesson),
+        Box::new(basics::control_flow::ControlFlowLesson),
+        Box::new(basics::ownership::OwnershipLesson),
+        Box::new(basics::structs::StructsLesson),
+        Box::new(basics::enums::EnumsLesson),
+        Box::new(basics::geometry::GeometryLesson),
+        Box::new(basics::packing::PackingLesson),
+        Box::new(basics::user::UserLesson),
+        Box::new(basics::color::ColorLesson),
+        Box::new(basics::message::MessageLesson),
+        Box::new(basics::message_codec::MessageCodecLesson),
+        Box::new(basics::ip::IpLesson),
+        Box::new(basics::vending::VendingLesson),
+        Box::new(advanced::lifetimes::LifetimesLesson),
+        Box::new(advanced::excerpt::ExcerptLesson),
+        Box::new(advanced::longest::LongestLesson),
+        Box::new(advanced::config::ConfigLesson),
+        Box::new(advanced::arena::ArenaLesson),
+        Box::new(advanced::traits::TraitsLesson),
+        Box::new(advanced::feed::FeedLesson),
+        Box::new(advanced::blanket::BlanketLesson),
+        Box::new(advanced::std_traits::StdTraitsLesson),
+        Box::new(advanced::generics::GenericsLesson),
+        Box::new(advanced::selection::SelectionLesson),
+        Box::new(advanced::containers::ContainersLesson),
+        Box::new(advanced::matrix::MatrixLesson),
+        Box::new(advanced::point::PointLesson),
+        Box::new(advanced::option_result::OptionResultLesson),
+        Box::new(advanced::closures::ClosuresLesson),
+        Box::new(advanced::threads::ThreadsLesson),
+        Box::new(advanced::macros::MacrosLesson),
+    ]
+}
+
+pub fn find(id: &str) -> Option<Box<dyn Lesson>> {
+    registry().into_iter().find(|lesson| lesson.id() == id)
+}
+
+// 运行时打印的分组标题
+pub fn group_title(group: &str) -> &'static str {
+    match group {
+        "basics" => "Rust Basics",
+        _ => "Rust Advanced",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // id 和分组都是从 `file!()` 的路径里取出来的
+    #[test]
+    fn id_and_group_come_from_the_path() {
+        let lesson = basics::ownership::OwnershipLesson;
+        assert_eq!(lesson.path(), "src/basics/ownership.rs");
+        assert_eq!(lesson.id(), "ownership");
+        assert_eq!(lesson.group(), "basics");
+        assert_eq!(find("message_codec").unwrap().group(), "basics");
+        assert_eq!(find("option_result").unwrap().group(), "advanced");
+        assert!(find("nope").is_none());
+    }
+
+    // 每节课的 id 唯一，并且源码里确实有对应的 `xxx_example`
+    #[test]
+    fn every_lesson_is_registered_once() {
+        let lessons = registry();
+        let mut ids: Vec<_> = lessons.iter().map(|lesson| lesson.id()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), lessons.len());
+        for lesson in &lessons {
+            let signature = format!("pub fn {}_example()", lesson.id());
+            assert!(lesson.source().contains(&signature), "{}", lesson.path());
+        }
+    }
+
+    // 注册代码和测试模块一样，不会出现在导出的课程源码里
+    #[test]
+    fn registration_is_left_out_of_lesson_lines() {
+        for lesson in registry() {
+            assert!(lesson.source().contains(corpus::REGISTRATION_MARKER));
+            let lines = corpus::lesson_lines(lesson.as_ref());
+            assert!(!lines.iter().any(|line| line.contains("impl crate::lesson::Lesson")));
+            assert!(lines.iter().any(|line| line.contains("_example()")));
+        }
+    }
+}
@@ -0,0 +1,7 @@
+// 课程和工具都放在库里，`main.rs` 只负责解析命令行。
+// 有了库目标，`cargo test` 除了各模块的单元测试，还会运行文档测试：
+// 课程里标注为 `compile_fail` 的错误写法必须真的无法编译，否则测试失败。
+pub mod basics;
+pub mod advanced;
+pub mod tools;
+pub mod lesson;
@@ -1,20 +1,43 @@
-mod basics;
-mod advanced;
+use rust_lessons::{lesson, tools};
 
 fn main() {
-    println!("--- Rust Basics ---");
-    basics::variables::variables_example();
-    basics::functions::functions_example();
-    basics::control_flow::control_flow_example();
-    basics::ownership::ownership_example();
-    basics::structs::structs_example();
-    basics::enums::enums_example();
+    // 不带参数时按顺序运行所有课程；带参数时执行对应的工具命令
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None => run_lessons(&[]),
+        Some("run") => run_lessons(&args[1..]),
+        Some("search") => tools::search::run(&args[1..]),
+        Some("cheatsheet") => tools::cheatsheet::run(&args[1..]),
+        Some("notebook") => tools::notebook::run(&args[1..]),
+        Some("anki") => tools::anki::run(&args[1..]),
+        Some(command) => {
+            eprintln!("未知命令: {}", command);
+            eprintln!("可用命令: run [lesson...] | search <term> | cheatsheet [topic] [--plain] | notebook [out_dir] | anki [out_file]");
+            std::process::exit(2);
+        }
+    }
+}
+
+// 按注册表的顺序运行课程，给出 id 时只运行这几节
+fn run_lessons(ids: &[String]) {
+    let lessons = lesson::registry();
+    let unknown: Vec<&String> = ids.iter().filter(|id| !lessons.iter().any(|l| l.id() == id.as_str())).collect();
+    if !unknown.is_empty() {
+        let known: Vec<&str> = lessons.iter().map(|l| l.id()).collect();
+        eprintln!("未知课程: {:?}", unknown);
+        eprintln!("可用课程: {}", known.join(" "));
+        std::process::exit(2);
+    }
 
-    println!("\n--- Rust Advanced ---");
-    advanced::lifetimes::lifetimes_example();
-    advanced::traits::traits_example();
-    advanced::generics::generics_example();
-    advanced::closures::closures_example();
-    advanced::threads::threads_example();
-    advanced::macros::macros_example();
+    let mut group = "";
+    for lesson in lessons.iter().filter(|l| ids.is_empty() || ids.iter().any(|id| id == l.id())) {
+        if lesson.group() != group {
+            if !group.is_empty() {
+                println!();
+            }
+            println!("--- {} ---", lesson::group_title(lesson.group()));
+            group = lesson.group();
+        }
+        lesson.run();
+    }
 }
@@ -0,0 +1,245 @@
+use std::fs;
+use std::path::Path;
+
+use super::corpus;
+use crate::lesson::{self, Lesson};
+
+// Anki 卡片导出
+// 生成 Anki 的纯文本导入格式：每行一张卡片，字段之间用制表符分隔，
+// 文件头的 `#` 指令告诉 Anki 分隔符、HTML 支持和标签所在的列。
+// 卡片来自三个地方：
+// 1. README 中每节课的小节标题 -> 正文（概念问答）
+// 2. 课程源码中带说明注释的小节 -> 说明和示例代码
+// 3. 源码里被注释掉、标着“错误！”的代码行 -> “为什么无法编译？”小测验
+// 每张卡片都带有课程 id 标签（例如 `ownership`、`lifetimes`），方便在 Anki 中按课筛选。
+
+pub struct Card {
+    pub front: String,
+    pub back: String,
+    pub tags: Vec<String>,
+}
+
+// 示例代码最多保留的行数，卡片太长就不适合复习了
+const MAX_CODE_LINES: usize = 15;
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// README 中的行内格式：`code` -> <code>，**粗体** -> <b>
+fn inline_html(text: &str) -> String {
+    let escaped = escape_html(text);
+    let mut out = String::new();
+    let mut in_code = false;
+    let mut in_bold = false;
+    let mut rest = escaped.as_str();
+
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("**") && !in_code {
+            out.push_str(if in_bold { "</b>" } else { "<b>" });
+            in_bold = !in_bold;
+            rest = &rest[2..];
+        } else if c == '`' {
+            out.push_str(if in_code { "</code>" } else { "<code>" });
+            in_code = !in_code;
+            rest = &rest[1..];
+        } else {
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    if in_code {
+        out.push_str("</code>");
+    }
+    if in_bold {
+        out.push_str("</b>");
+    }
+    out
+}
+
+// 代码块保留缩进和换行：<pre><code>，换行在写文件时统一变成 <br>
+fn code_html(lines: &[&str]) -> String {
+    let mut lines: Vec<&str> = lines.to_vec();
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    let truncated = lines.len() > MAX_CODE_LINES;
+    lines.truncate(MAX_CODE_LINES);
+
+    let mut code = escape_html(&corpus::dedent(&lines));
+    if truncated {
+        code.push_str("\n// ...");
+    }
+    format!("<pre><code>{}</code></pre>", code)
+}
+
+fn tags(lesson: &dyn Lesson, kind: &str) -> Vec<String> {
+    vec![lesson.id().to_string(), lesson.group().to_string(), kind.to_string()]
+}
+
+fn readme_cards(lesson: &dyn Lesson, cards: &mut Vec<Card>) {
+    let chapter = lesson.sections();
+    let Some(chapter_title) = chapter.first().map(|section| section.title) else {
+        return;
+    };
+
+    for section in &chapter {
+        let mut paragraphs: Vec<String> = Vec::new();
+        let mut code: Vec<&str> = Vec::new();
+        let mut in_code = false;
+
+        for &(_, line) in &section.lines {
+            if line.trim_start().starts_with("```") {
+                if in_code {
+                    paragraphs.push(code_html(&code));
+                    code.clear();
+                }
+                in_code = !in_code;
+            } else if in_code {
+                // `// in src/...` 只是指向源码位置的注释，卡片里不需要
+                if !line.trim_start().starts_with("// in src/") {
+                    code.push(line);
+                }
+            } else if !line.trim().is_empty() {
+                paragraphs.push(inline_html(line.trim()));
+            }
+        }
+        if paragraphs.is_empty() {
+            continue;
+        }
+
+        let front = if section.title == chapter_title {
+            format!("{}：这一章讲的是什么？", inline_html(section.title))
+        } else {
+            format!("{}<br><small>{}</small>", inline_html(section.title), escape_html(chapter_title))
+        };
+        cards.push(Card {
+            front,
+            back: paragraphs.join("\n"),
+            tags: tags(lesson, "readme"),
+        });
+    }
+}
+
+fn source_cards(lesson: &dyn Lesson, cards: &mut Vec<Card>) {
+    let parts = corpus::split_lesson(lesson);
+
+    for section in corpus::split_sections(&parts.statements) {
+        // 只有“标题 + 说明”的多行注释才适合做成问答
+        let notes: Vec<&str> = section
+            .notes
+            .iter()
+            .map(|line| line.trim_start().trim_start_matches("//").trim())
+            .collect();
+        let Some((title, explanation)) = notes.split_first() else {
+            continue;
+        };
+        if explanation.is_empty() {
+            continue;
+        }
+
+        let mut back = explanation.iter().map(|line| inline_html(line)).collect::<Vec<_>>();
+        if section.code.iter().any(|line| !line.trim().is_empty()) {
+            back.push(code_html(&section.code));
+        }
+        cards.push(Card {
+            front: format!("{}<br><small>{}</small>", escape_html(title), escape_html(lesson.path())),
+            back: back.join("\n"),
+            tags: tags(lesson, "source"),
+        });
+    }
+}
+
+// 形如 `// let r2 = &mut s; // 错误！原因` 的注释行。
+// “错误！”后面没有写原因时，用它上方的说明注释（跳过被注释掉的代码）作为答案。
+fn quiz_cards(lesson: &dyn Lesson, cards: &mut Vec<Card>) {
+    let lines = corpus::lesson_lines(lesson);
+
+    for (index, line) in lines.iter().enumerate() {
+        let Some(commented) = line.trim_start().strip_prefix("// ") else {
+            continue;
+        };
+        let Some((code, reason)) = commented.split_once("// 错误！") else {
+            continue;
+        };
+        let code = code.trim();
+        if code.is_empty() {
+            continue;
+        }
+
+        let reason = reason.trim();
+        let back = if reason.is_empty() {
+            let mut notes: Vec<String> = lines[..index]
+                .iter()
+                .rev()
+                .map_while(|line| line.trim_start().strip_prefix("//"))
+                .map(str::trim)
+                .filter(|note| !note.ends_with(';') && !note.ends_with('{') && !note.ends_with('}'))
+                .map(inline_html)
+                .collect();
+            notes.reverse();
+            notes.join("\n")
+        } else {
+            inline_html(reason)
+        };
+        if back.is_empty() {
+            continue;
+        }
+        cards.push(Card {
+            front: format!("这行代码为什么无法编译？{}", code_html(&[code])),
+            back,
+            tags: tags(lesson, "quiz"),
+        });
+    }
+}
+
+pub fn build_cards() -> Vec<Card> {
+    let mut cards = Vec::new();
+    for lesson in lesson::registry() {
+        readme_cards(lesson.as_ref(), &mut cards);
+        source_cards(lesson.as_ref(), &mut cards);
+        quiz_cards(lesson.as_ref(), &mut cards);
+    }
+    cards
+}
+
+// 字段中不能出现制表符和换行：制表符换成空格，换行换成 <br>
+fn field(text: &str) -> String {
+    text.replace('\t', "    ").replace('\n', "<br>")
+}
+
+pub fn deck_text(cards: &[Card]) -> String {
+    let mut out = String::from("#separator:tab\n#html:true\n#notetype:Basic\n#deck:Rust 学习指南\n#tags column:3\n");
+    for card in cards {
+        out.push_str(&format!(
+            "{}\t{}\t{}\n",
+            field(&card.front),
+            field(&card.back),
+            card.tags.join(" ")
+        ));
+    }
+    out
+}
+
+// `anki [out_file]` 命令的入口
+pub fn run(args: &[String]) {
+    let path = Path::new(args.first().map(String::as_str).unwrap_or("decks/rust_lessons.txt"));
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty())
+        && let Err(error) = fs::create_dir_all(parent)
+    {
+        eprintln!("无法创建目录 {}: {}", parent.display(), error);
+        std::process::exit(1);
+    }
+
+    let cards = build_cards();
+    match fs::write(path, deck_text(&cards)) {
+        Ok(()) => println!("已导出 {} 张卡片到 {}，在 Anki 中选择“文件 -> 导入”即可。", cards.len(), path.display()),
+        Err(error) => {
+            eprintln!("无法写入 {}: {}", path.display(), error);
+            std::process::exit(1);
+        }
+    }
+}
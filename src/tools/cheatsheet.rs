@@ -0,0 +1,333 @@
+use super::corpus;
+use crate::lesson::{self, Lesson};
+
+// 速查表（Cheatsheet）
+// 每个主题列出几种常用语法形式，并配上一段直接从课程源码中截取的最小示例。
+// 示例通过锚点文本定位：锚点找不到时命令直接报错，而不是悄悄输出过期内容。
+// 语法形式也一样：每个形式的代码必须原样出现在课程源码的某一行里（测试模块除外），
+// 课程改了写法而速查表没跟上时，生成会失败。
+
+struct Entry {
+    lesson: &'static str,
+    title: &'static str,
+    forms: &'static [Form],
+    examples: &'static [Example],
+}
+
+// 一种语法形式：`code` 是课程源码中的一段原文，`note` 是附在后面的说明
+struct Form {
+    code: &'static str,
+    note: Option<&'static str>,
+}
+
+const fn form(code: &'static str, note: &'static str) -> Form {
+    Form { code, note: Some(note) }
+}
+
+const fn bare(code: &'static str) -> Form {
+    Form { code, note: None }
+}
+
+// 从包含 `from` 的行开始截取；`to` 为 None 时截取整个项
+struct Example {
+    from: &'static str,
+    to: Option<&'static str>,
+}
+
+const ENTRIES: &[Entry] = &[
+    Entry {
+        lesson: "ownership",
+        title: "移动与克隆",
+        forms: &[form("let s2 = s1;", "移动，s1 失效"), form("let s2 = s1.clone();", "深拷贝")],
+        examples: &[
+            Example { from: "let s1 = String::from(\"hello\");", to: Some("println!(\"{}\", s2);") },
+            Example { from: "let s2 = s1.clone();", to: Some("println!(\"s1 = ") },
+        ],
+    },
+    Entry {
+        lesson: "ownership",
+        title: "引用与借用",
+        forms: &[
+            form("fn calculate_length(s: &String) -> usize", "&T 不可变借用，可以同时存在多个"),
+            form("fn change(some_string: &mut String)", "&mut T 可变借用，同一时间只能有一个"),
+        ],
+        examples: &[
+            Example { from: "fn calculate_length(", to: None },
+            Example { from: "fn change(", to: None },
+        ],
+    },
+    Entry {
+        lesson: "structs",
+        title: "方法与关联函数",
+        forms: &[form("fn area(&self) -> u32", "方法"), form("fn square(size: u32) -> Rectangle", "关联函数，Rectangle::square(3)")],
+        examples: &[Example { from: "impl Rectangle {", to: None }],
+    },
+    Entry {
+        lesson: "enums",
+        title: "match 与 if let",
+        forms: &[form("match coin {", "每个分支 Pattern => expr，必须覆盖所有情况"), form("if let Some(max) = config_max {", "只关心一种模式")],
+        examples: &[
+            Example { from: "fn value_in_cents(", to: None },
+            Example { from: "if let Some(max) = config_max", to: None },
+        ],
+    },
+    Entry {
+        lesson: "lifetimes",
+        title: "生命周期注解",
+        forms: &[
+            bare("fn longest<'a>(x: &'a str, y: &'a str) -> &'a str"),
+            bare("struct ImportantExcerpt<'a> {"),
+            bare("let s: &'static str"),
+        ],
+        examples: &[
+            Example { from: "fn longest<'a>", to: None },
+            Example { from: "struct ImportantExcerpt<'a>", to: None },
+        ],
+    },
+    Entry {
+        lesson: "traits",
+        title: "impl Trait / <T: Trait> / &dyn Trait",
+        forms: &[
+            form("fn notify(item: &impl Summary)", "静态分发，语法糖"),
+            form("fn notify_trait_bound<T: Summary>(item: &T)", "静态分发，完整写法"),
+            form("fn notify_trait_object(item: &dyn Summary)", "动态分发，特征对象"),
+        ],
+        examples: &[
+            Example { from: "pub fn notify(", to: None },
+            Example { from: "pub fn notify_trait_bound<", to: None },
+            Example { from: "pub fn notify_trait_object(", to: None },
+        ],
+    },
+    Entry {
+        lesson: "generics",
+        title: "泛型函数与泛型结构体",
+        forms: &[bare("fn largest<T: PartialOrd + Copy>(list: &[T]) -> T"), bare("struct Point<T> {"), bare("impl<T> Point<T> {")],
+        examples: &[
+            Example { from: "fn largest<T", to: None },
+            Example { from: "impl Point<f32>", to: None },
+        ],
+    },
+    Entry {
+        lesson: "closures",
+        title: "Fn / FnMut / FnOnce",
+        forms: &[
+            form("let equal_to_x = |z| z == x;", "不可变借用捕获 x，实现 Fn，可多次调用"),
+            form("let equal_to_x = move |z| z == x;", "move 强制获取所有权"),
+            form("F: FnOnce() -> i32,", "FnOnce 至少能调用一次；FnMut 可变借用捕获，可多次调用"),
+        ],
+        examples: &[
+            Example { from: "fn apply_function<F>", to: None },
+            Example { from: "let greeting = String::from", to: Some("let result = apply_function") },
+        ],
+    },
+    Entry {
+        lesson: "threads",
+        title: "spawn 与 join",
+        forms: &[bare("let handle = thread::spawn(move || {"), form("handle.join().unwrap();", "等待线程结束")],
+        examples: &[Example { from: "let handle = thread::spawn(move ||", to: Some("handle.join().unwrap();") }],
+    },
+    Entry {
+        lesson: "threads",
+        title: "Arc<Mutex<T>>",
+        forms: &[
+            bare("Arc::new(Mutex::new(0))"),
+            form("Arc::clone(&counter)", "只增加引用计数"),
+            form("counter.lock().unwrap()", "返回的守卫离开作用域时解锁"),
+        ],
+        examples: &[Example { from: "let counter = Arc::new(Mutex::new(0));", to: Some("println!(\"Result") }],
+    },
+    Entry {
+        lesson: "macros",
+        title: "声明宏",
+        forms: &[bare("macro_rules! my_vec {"), form("($($x:expr),*) => {", "重复匹配")],
+        examples: &[Example { from: "macro_rules! my_vec", to: None }],
+    },
+];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Format {
+    Markdown,
+    Plain,
+}
+
+struct Rendered {
+    lesson: Box<dyn Lesson>,
+    title: String,
+    summary: String,
+    entries: Vec<RenderedEntry>,
+}
+
+struct RenderedEntry {
+    title: &'static str,
+    forms: Vec<String>,
+    examples: Vec<corpus::Snippet>,
+}
+
+// 每个语法形式都必须出现在课程源码中，返回渲染用的文本
+fn check_forms(lesson: &dyn Lesson, forms: &[Form]) -> Result<Vec<String>, String> {
+    let lines = corpus::lesson_lines(lesson);
+    forms
+        .iter()
+        .map(|form| {
+            if !lines.iter().any(|line| line.contains(form.code)) {
+                return Err(format!("{} 中找不到语法形式 `{}`，请更新速查表", lesson.path(), form.code));
+            }
+            Ok(match form.note {
+                Some(note) => format!("{}  // {}", form.code, note),
+                None => form.code.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn collect(topic: Option<&str>) -> Result<Vec<Rendered>, String> {
+    let mut topics: Vec<Rendered> = Vec::new();
+
+    for entry in ENTRIES {
+        if topic.is_some_and(|topic| topic != entry.lesson) {
+            continue;
+        }
+        let lesson = lesson::find(entry.lesson)
+            .ok_or_else(|| format!("未知的课程: {}", entry.lesson))?;
+
+        let mut examples = Vec::new();
+        for example in entry.examples {
+            let snippet = corpus::extract(lesson.source(), example.from, example.to).ok_or_else(|| {
+                format!("{} 中找不到示例 `{}`，请更新速查表的锚点", lesson.path(), example.from)
+            })?;
+            examples.push(snippet);
+        }
+
+        let rendered = RenderedEntry {
+            title: entry.title,
+            forms: check_forms(lesson.as_ref(), entry.forms)?,
+            examples,
+        };
+        match topics.iter_mut().find(|t| t.lesson.id() == lesson.id()) {
+            Some(existing) => existing.entries.push(rendered),
+            None => {
+                let chapter = lesson.sections();
+                let (title, summary) = match chapter.first() {
+                    Some(section) => (section.title.to_string(), corpus::first_paragraph(section)),
+                    None => (lesson.title().to_string(), String::new()),
+                };
+                topics.push(Rendered {
+                    lesson,
+                    title,
+                    summary,
+                    entries: vec![rendered],
+                });
+            }
+        }
+    }
+
+    match topic {
+        Some(topic) if topics.is_empty() => Err(format!("没有主题 `{}` 的速查内容", topic)),
+        _ => Ok(topics),
+    }
+}
+
+pub fn render(topic: Option<&str>, format: Format) -> Result<String, String> {
+    let topics = collect(topic)?;
+    let mut out = String::new();
+
+    match format {
+        Format::Markdown => out.push_str("# Rust 速查表\n\n> 由 `cargo run -- cheatsheet` 从课程源码生成，请勿手动编辑。\n"),
+        Format::Plain => out.push_str("Rust 速查表\n===========\n"),
+    }
+
+    for topic in &topics {
+        match format {
+            Format::Markdown => out.push_str(&format!("\n## {}\n\n", topic.title)),
+            Format::Plain => out.push_str(&format!("\n{}\n{}\n\n", topic.title, "-".repeat(40))),
+        }
+        if !topic.summary.is_empty() {
+            out.push_str(&topic.summary);
+            out.push_str("\n\n");
+        }
+
+        for entry in &topic.entries {
+            match format {
+                Format::Markdown => out.push_str(&format!("### {}\n\n", entry.title)),
+                Format::Plain => out.push_str(&format!("* {}\n", entry.title)),
+            }
+            for form in &entry.forms {
+                match format {
+                    Format::Markdown => out.push_str(&format!("- `{}`\n", form)),
+                    Format::Plain => out.push_str(&format!("    - {}\n", form)),
+                }
+            }
+            out.push('\n');
+
+            for example in &entry.examples {
+                let location = format!("{}:{}", topic.lesson.path(), example.line);
+                match format {
+                    Format::Markdown => {
+                        out.push_str(&format!("```rust\n// {}\n{}\n```\n\n", location, example.code));
+                    }
+                    Format::Plain => {
+                        out.push_str(&format!("    ({})\n", location));
+                        for line in example.code.lines() {
+                            out.push_str(&format!("    | {}\n", line));
+                        }
+                        out.push('\n');
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+// `cheatsheet [topic] [--plain]` 命令的入口
+pub fn run(args: &[String]) {
+    let format = if args.iter().any(|arg| arg == "--plain") {
+        Format::Plain
+    } else {
+        Format::Markdown
+    };
+    let topic = args.iter().find(|arg| !arg.starts_with("--")).map(String::as_str);
+
+    match render(topic, format) {
+        Ok(text) => print!("{}", text),
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 课程改了写法之后，`cargo test` 就能发现速查表过期了
+    #[test]
+    fn every_form_and_example_is_found() {
+        for format in [Format::Markdown, Format::Plain] {
+            if let Err(message) = render(None, format) {
+                panic!("{}", message);
+            }
+        }
+    }
+
+    #[test]
+    fn missing_form_is_an_error() {
+        let lesson = lesson::find("ownership").unwrap();
+        assert_eq!(
+            check_forms(lesson.as_ref(), &[form("let s2 = s1;", "移动")]),
+            Ok(vec![String::from("let s2 = s1;  // 移动")])
+        );
+        let error = check_forms(lesson.as_ref(), &[bare("let s3 = s1;")]).unwrap_err();
+        assert!(error.contains("找不到语法形式 `let s3 = s1;`"), "{}", error);
+    }
+
+    // 测试模块里的代码不算
+    #[test]
+    fn forms_in_tests_do_not_count() {
+        let lesson = lesson::find("ip").unwrap();
+        assert!(lesson.source().contains("fn check_against_std(s: &str)"));
+        assert!(check_forms(lesson.as_ref(), &[bare("fn check_against_std(s: &str)")]).is_err());
+    }
+}
@@ -0,0 +1,318 @@
+use crate::lesson::Lesson;
+
+// 语料（Corpus）
+// 工具命令（搜索、速查表、导出器）都从这里读取 README 和课程源码。
+// 课程源码由 `crate::lesson` 的注册表提供，README 和源码都在编译期嵌入，
+// 所以工具输出永远和当前的课程代码保持一致。
+
+pub const README_PATH: &str = "README.md";
+pub const README: &str = include_str!("../../README.md");
+
+// README 中的一个章节：标题行加上直到下一个标题之前的所有行。
+// 行号从 1 开始，和编辑器里看到的一致。
+pub struct ReadmeSection {
+    pub level: usize,
+    pub title: &'static str,
+    pub line: usize,
+    pub lines: Vec<(usize, &'static str)>,
+}
+
+// 按标题切分 README。代码块（```）里的 `#` 开头的行（比如 `#[derive(Debug)]`）不是标题。
+pub fn readme_sections() -> Vec<ReadmeSection> {
+    let mut sections: Vec<ReadmeSection> = Vec::new();
+    let mut in_code = false;
+
+    for (index, line) in README.lines().enumerate() {
+        let number = index + 1;
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+        }
+
+        let level = line.chars().take_while(|&c| c == '#').count();
+        if !in_code && level > 0 && line[level..].starts_with(' ') {
+            sections.push(ReadmeSection {
+                level,
+                title: line[level..].trim(),
+                line: number,
+                lines: Vec::new(),
+            });
+        } else if let Some(section) = sections.last_mut() {
+            section.lines.push((number, line));
+        }
+    }
+
+    sections
+}
+
+// 找到某节课在 README 中的 `###` 章节，以及它下面所有更深层的小节。
+// README 里有重复的章节时，取第一个。
+pub fn readme_chapter(title: &str) -> Vec<ReadmeSection> {
+    let marker = format!("({})", title);
+    let mut chapter = Vec::new();
+    let mut inside = false;
+
+    for section in readme_sections() {
+        if section.level <= 3 {
+            if inside {
+                break;
+            }
+            inside = section.level == 3 && section.title.ends_with(&marker);
+        }
+        if inside {
+            chapter.push(section);
+        }
+    }
+
+    chapter
+}
+
+// 章节标题下的第一段正文，用作简介
+pub fn first_paragraph(section: &ReadmeSection) -> String {
+    section
+        .lines
+        .iter()
+        .map(|&(_, line)| line.trim())
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.is_empty() && !line.starts_with("```"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// 一行代码对花括号深度的影响。字符串、字符字面值和行注释里的括号不计入，
+// `'a` 这样的生命周期不会被误认为字符字面值。
+pub fn brace_delta(line: &str) -> i32 {
+    let chars: Vec<char> = line.chars().collect();
+    let mut delta = 0;
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '/' if chars.get(i + 1) == Some(&'/') => break,
+            '"' => {
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    if chars[i] == '\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            '\'' => {
+                if chars.get(i + 1) == Some(&'\\') {
+                    i += 2;
+                    while i + 1 < chars.len() && chars[i + 1] != '\'' {
+                        i += 1;
+                    }
+                    i += 1;
+                } else if chars.get(i + 2) == Some(&'\'') {
+                    i += 2;
+                }
+            }
+            '{' => delta += 1,
+            '}' => delta -= 1,
+            _ => {}
+        }
+        i += 1;
+    }
+
+    delta
+}
+
+// 源码片段：从包含 `from` 的行开始。
+// 给出 `to` 时截取到第一个包含 `to` 的行；否则截取一个完整的项（花括号配平为止）。
+pub struct Snippet {
+    pub line: usize,
+    pub code: String,
+}
+
+pub fn extract(text: &str, from: &str, to: Option<&str>) -> Option<Snippet> {
+    let lines: Vec<&str> = text.lines().collect();
+    let start = lines.iter().position(|line| line.contains(from))?;
+
+    let end = match to {
+        Some(to) => start + lines[start..].iter().position(|line| line.contains(to))?,
+        None => {
+            let mut depth = 0;
+            let mut opened = false;
+            let mut end = None;
+            for (offset, line) in lines[start..].iter().enumerate() {
+                depth += brace_delta(line);
+                opened |= line.contains('{');
+                if (opened && depth <= 0) || (!opened && line.trim_end().ends_with(';')) {
+                    end = Some(start + offset);
+                    break;
+                }
+            }
+            end?
+        }
+    };
+
+    Some(Snippet {
+        line: start + 1,
+        code: dedent(&lines[start..=end]),
+    })
+}
+
+// 去掉所有非空行共同的缩进
+pub fn dedent(lines: &[&str]) -> String {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|line| if line.len() >= indent { &line[indent..] } else { line.trim_start() })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// 课程文件里 `impl Lesson` 注册代码前面的标记注释，从这一行到注册代码结束都会被跳过
+pub const REGISTRATION_MARKER: &str = "// 课程注册，见 lesson.rs";
+
+// 课程源码中除去 `#[cfg(test)]` 测试模块和课程注册代码以外的行。
+// 测试只在 `cargo test` 时编译，注册代码只是给工具用的，导出的笔记本和卡片只需要课程本身
+pub fn lesson_lines(lesson: &dyn Lesson) -> Vec<&'static str> {
+    let mut lines = Vec::new();
+    let mut skipping = false;
+    let mut depth = 0;
+    let mut opened = false;
+    for line in lesson.source().lines() {
+        if !skipping && (line.trim() == "#[cfg(test)]" || line.trim() == REGISTRATION_MARKER) {
+            skipping = true;
+            depth = 0;
+            opened = false;
+            continue;
+        }
+        if skipping {
+            depth += brace_delta(line);
+            opened |= line.contains('{');
+            skipping = !opened || depth > 0;
+            continue;
+        }
+        lines.push(line);
+    }
+    // 测试模块和注册代码前面的空行也一起去掉
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+// 课程源码拆分后的结果
+pub struct LessonParts<'a> {
+    pub definitions: Vec<&'a str>,
+    pub statements: Vec<&'a str>,
+}
+
+const ITEM_KEYWORDS: &[&str] = &[
+    "fn ", "struct ", "enum ", "impl ", "impl<", "trait ", "use ", "mod ", "const ", "static ",
+    "type ", "macro_rules!", "#[",
+];
+
+fn is_item_start(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    let trimmed = trimmed.strip_prefix("pub ").unwrap_or(trimmed);
+    ITEM_KEYWORDS.iter().any(|keyword| trimmed.starts_with(keyword))
+}
+
+// 把一个课程文件分成两部分：
+// 定义（模块级的项，以及课程函数体里嵌套定义的 fn/struct/impl 等）和课程函数体中的语句。
+pub fn split_lesson(lesson: &dyn Lesson) -> LessonParts<'static> {
+    let signature = format!("pub fn {}_example()", lesson.id());
+    let mut parts = LessonParts {
+        definitions: Vec::new(),
+        statements: Vec::new(),
+    };
+
+    let mut in_example = false;
+    let mut example_depth = 0;
+    let mut item_depth = 0;
+    let mut in_item = false;
+    let mut item_opened = false;
+
+    for line in lesson_lines(lesson) {
+        if !in_example && line.starts_with(&signature) {
+            in_example = true;
+            example_depth = brace_delta(line);
+            continue;
+        }
+
+        if !in_example {
+            parts.definitions.push(line);
+            continue;
+        }
+
+        let delta = brace_delta(line);
+        if !in_item && example_depth == 1 && delta < 0 && line.trim() == "}" {
+            // 课程函数结束
+            in_example = false;
+            example_depth = 0;
+            continue;
+        }
+
+        if !in_item && example_depth == 1 && is_item_start(line) {
+            in_item = true;
+            item_depth = 0;
+            item_opened = false;
+        }
+
+        if in_item {
+            parts.definitions.push(line);
+            item_depth += delta;
+            item_opened |= line.contains('{');
+            let finished = if item_opened {
+                item_depth <= 0
+            } else {
+                line.trim_end().ends_with(';')
+            };
+            if finished {
+                in_item = false;
+                parts.definitions.push("");
+            }
+        } else {
+            parts.statements.push(line);
+        }
+        example_depth += delta;
+    }
+
+    parts
+}
+
+// 一个注释小节：开头的连续注释行（说明文字）加上后面的代码
+pub struct Section<'a> {
+    pub notes: Vec<&'a str>,
+    pub code: Vec<&'a str>,
+}
+
+pub fn split_sections<'a>(lines: &[&'a str]) -> Vec<Section<'a>> {
+    let mut sections: Vec<Section<'a>> = Vec::new();
+    let mut previous_blank = true;
+
+    for &line in lines {
+        let trimmed = line.trim();
+        let is_comment = trimmed.starts_with("//");
+        let starts_section = is_comment && previous_blank;
+        previous_blank = trimmed.is_empty();
+
+        if starts_section || sections.is_empty() {
+            sections.push(Section {
+                notes: Vec::new(),
+                code: Vec::new(),
+            });
+        }
+        let section = sections.last_mut().expect("at least one section");
+        if is_comment && section.code.is_empty() {
+            section.notes.push(line);
+        } else {
+            section.code.push(line);
+        }
+    }
+
+    sections.retain(|section| {
+        !section.notes.is_empty() || section.code.iter().any(|line| !line.trim().is_empty())
+    });
+    sections
+}
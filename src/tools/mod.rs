@@ -0,0 +1,5 @@
+pub mod corpus;
+pub mod search;
+pub mod cheatsheet;
+pub mod notebook;
+pub mod anki;
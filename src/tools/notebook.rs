@@ -0,0 +1,428 @@
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
+
+use super::corpus;
+use crate::lesson::{self, Lesson};
+
+// Jupyter 笔记本导出（evcxr 内核）
+// 每节课生成一个 `.ipynb`：
+// 1. README 中对应章节的正文变成 markdown 单元格（代码块交给下面的代码单元格）。
+// 2. 模块级的 `use`、结构体、辅助函数，以及课程函数体里嵌套定义的
+//    `fn`/`struct`/`enum`/`impl`/`trait`/`macro_rules!`，统一放进第一个代码单元格。
+//    函数体内的项在整个函数中都可见，可以“先使用后定义”；
+//    evcxr 的单元格却是按顺序执行的，所以必须先把定义全部提上来。
+// 3. 剩下的语句按注释小节切分，每个小节一个代码单元格。后面的小节用到前面小节的
+//    变量时，把它们合并到同一个单元格里，再用 `{ ... }` 包起来。
+//    这样借用（`&str`）和闭包这类 evcxr 无法跨单元格保存的值都不会越过单元格边界。
+// 4. 有的课程用到了其他课程的类型（`use super::geometry::Rectangle;`）。evcxr 里没有 `super`，
+//    所以把被依赖的课程模块（递归地）内联成 `mod geometry { ... }`，放在定义之前的单元格里，
+//    再把路径改写成 `crate::geometry::Rectangle`。引用了课程以外的模块时无法内联，这节课不导出。
+
+// 最小的 JSON 值，只覆盖笔记本格式用得到的部分
+enum Json {
+    Null,
+    Number(i64),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    fn write(&self, out: &mut String, indent: usize) {
+        match self {
+            Json::Null => out.push_str("null"),
+            Json::Number(n) => out.push_str(&n.to_string()),
+            Json::Str(s) => write_json_string(out, s),
+            Json::Array(items) if items.is_empty() => out.push_str("[]"),
+            Json::Object(fields) if fields.is_empty() => out.push_str("{}"),
+            Json::Array(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    out.push_str(if i == 0 { "\n" } else { ",\n" });
+                    out.push_str(&" ".repeat(indent + 1));
+                    item.write(out, indent + 1);
+                }
+                out.push('\n');
+                out.push_str(&" ".repeat(indent));
+                out.push(']');
+            }
+            Json::Object(fields) => {
+                out.push('{');
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    out.push_str(if i == 0 { "\n" } else { ",\n" });
+                    out.push_str(&" ".repeat(indent + 1));
+                    write_json_string(out, key);
+                    out.push_str(": ");
+                    value.write(out, indent + 1);
+                }
+                out.push('\n');
+                out.push_str(&" ".repeat(indent));
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+enum Cell {
+    Markdown(String),
+    Code(String),
+}
+
+impl Cell {
+    // nbformat 要求 source 是按行切分的字符串数组，除最后一行外都保留换行符
+    fn to_json(&self) -> Json {
+        let (kind, text) = match self {
+            Cell::Markdown(text) => ("markdown", text),
+            Cell::Code(text) => ("code", text),
+        };
+        let lines: Vec<&str> = text.split('\n').collect();
+        let source = lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                if i + 1 < lines.len() {
+                    Json::Str(format!("{}\n", line))
+                } else {
+                    Json::Str(line.to_string())
+                }
+            })
+            .collect();
+
+        let mut fields = vec![("cell_type", Json::Str(kind.to_string()))];
+        if let Cell::Code(_) = self {
+            fields.push(("execution_count", Json::Null));
+        }
+        fields.push(("metadata", Json::Object(Vec::new())));
+        if let Cell::Code(_) = self {
+            fields.push(("outputs", Json::Array(Vec::new())));
+        }
+        fields.push(("source", Json::Array(source)));
+        Json::Object(fields)
+    }
+}
+
+fn notebook_json(cells: &[Cell]) -> String {
+    let kernelspec = Json::Object(vec![
+        ("display_name", Json::Str("Rust".to_string())),
+        ("language", Json::Str("rust".to_string())),
+        ("name", Json::Str("rust".to_string())),
+    ]);
+    let language_info = Json::Object(vec![
+        ("codemirror_mode", Json::Str("rust".to_string())),
+        ("file_extension", Json::Str(".rs".to_string())),
+        ("mimetype", Json::Str("text/rust".to_string())),
+        ("name", Json::Str("Rust".to_string())),
+        ("pygment_lexer", Json::Str("rust".to_string())),
+        ("version", Json::Str(String::new())),
+    ]);
+    let notebook = Json::Object(vec![
+        ("cells", Json::Array(cells.iter().map(Cell::to_json).collect())),
+        (
+            "metadata",
+            Json::Object(vec![("kernelspec", kernelspec), ("language_info", language_info)]),
+        ),
+        ("nbformat", Json::Number(4)),
+        ("nbformat_minor", Json::Number(4)),
+    ]);
+
+    let mut out = String::new();
+    notebook.write(&mut out, 0);
+    out.push('\n');
+    out
+}
+
+// 一行代码中出现的标识符（忽略字符串和注释）
+fn identifiers(line: &str) -> Vec<String> {
+    let code = line.split("//").next().unwrap_or("");
+    let mut names = Vec::new();
+    let mut current = String::new();
+    let mut in_string = false;
+    let mut previous = ' ';
+
+    for c in code.chars() {
+        if c == '"' && previous != '\\' {
+            in_string = !in_string;
+        } else if !in_string && (c.is_alphanumeric() || c == '_') {
+            current.push(c);
+        } else if !current.is_empty() {
+            names.push(std::mem::take(&mut current));
+        }
+        previous = c;
+    }
+    if !current.is_empty() {
+        names.push(current);
+    }
+    names.retain(|name| !name.starts_with(|c: char| c.is_ascii_digit()));
+    names
+}
+
+// `let` 语句绑定的名字，以及等号右边用到的标识符
+fn let_binding(line: &str) -> Option<(Vec<String>, Vec<String>)> {
+    let rest = line.trim_start().strip_prefix("let ")?;
+    let (pattern, value) = rest.split_once('=')?;
+    let pattern = pattern.split(':').next().unwrap_or(pattern);
+    let bound = identifiers(pattern)
+        .into_iter()
+        .filter(|name| name != "mut")
+        .collect();
+    Some((bound, identifiers(value)))
+}
+
+// 合并互相依赖的小节：第 j 个小节用到了第 i 个小节绑定的变量时，i..=j 必须在同一个单元格里
+fn group_sections(sections: &[corpus::Section<'_>]) -> Vec<(usize, usize)> {
+    let mut bound_in: Vec<(String, usize)> = Vec::new();
+    let mut group_start: Vec<usize> = (0..sections.len()).collect();
+
+    // 只有函数体最外层的绑定才能被后面的小节看到，内层代码块里的遮蔽不算
+    let mut depth = 0;
+
+    for (index, section) in sections.iter().enumerate() {
+        let mut local: Vec<String> = Vec::new();
+        for line in &section.code {
+            let outermost = depth == 0;
+            depth += corpus::brace_delta(line);
+            let (bindings, used) = match let_binding(line) {
+                Some((bindings, used)) => (bindings, used),
+                None => (Vec::new(), identifiers(line)),
+            };
+            for name in used {
+                if local.contains(&name) {
+                    continue;
+                }
+                let owner = bound_in.iter().rev().find(|(bound, _)| *bound == name);
+                if let Some(&(_, owner)) = owner.filter(|&&(_, owner)| owner < index) {
+                    group_start[index] = group_start[index].min(owner);
+                }
+            }
+            for name in bindings.into_iter().filter(|_| outermost) {
+                local.push(name.clone());
+                bound_in.push((name, index));
+            }
+        }
+    }
+
+    // 依赖区间可能重叠，从后往前把它们展开成互不相交的连续分组
+    let mut groups = Vec::new();
+    let mut end = sections.len();
+    while end > 0 {
+        let mut start = end - 1;
+        let mut index = end;
+        while index > start {
+            index -= 1;
+            start = start.min(group_start[index]);
+        }
+        groups.push((start, end - 1));
+        end = start;
+    }
+    groups.reverse();
+    groups
+}
+
+// 小节开头的注释变成 markdown：多行注释的第一行是小节标题
+fn comment_text(lines: &[&str]) -> String {
+    let lines: Vec<&str> = lines
+        .iter()
+        .map(|line| {
+            let trimmed = line.trim_start().trim_start_matches("//");
+            trimmed.strip_prefix(' ').unwrap_or(trimmed)
+        })
+        .collect();
+
+    match lines.split_first() {
+        Some((title, rest)) if !rest.is_empty() => format!("#### {}\n\n{}", title, rest.join("\n")),
+        _ => lines.join("\n"),
+    }
+}
+
+// 课程文件中课程函数以外的部分，也就是其他模块能看到的那些项
+fn module_items(lesson: &dyn Lesson) -> Vec<&'static str> {
+    let signature = format!("pub fn {}_example()", lesson.id());
+    let mut items = Vec::new();
+    let mut depth = 0;
+    for line in corpus::lesson_lines(lesson) {
+        if depth == 0 && line.starts_with(&signature) {
+            depth = corpus::brace_delta(line);
+        } else if depth > 0 {
+            depth += corpus::brace_delta(line);
+        } else {
+            items.push(line);
+        }
+    }
+    items
+}
+
+// 笔记本里所有课程模块都在 crate 根下：
+// `use super::x::...` 和 `crate::basics::x::...`、`crate::advanced::x::...` 都改写成 `crate::x::...`
+fn rewrite_paths(line: &str) -> String {
+    let line = match line.trim_start().strip_prefix("use super::") {
+        Some(rest) => format!("{}use crate::{}", &line[..line.len() - line.trim_start().len()], rest),
+        None => line.to_string(),
+    };
+    line.replace("crate::basics::", "crate::").replace("crate::advanced::", "crate::")
+}
+
+// 改写后的一行代码通过 `crate::x` 引用到的模块名（忽略注释）
+fn crate_references(line: &str) -> Vec<String> {
+    let code = line.split("//").next().unwrap_or("");
+    code.match_indices("crate::")
+        .map(|(i, prefix)| {
+            code[i + prefix.len()..]
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect()
+        })
+        .collect()
+}
+
+// 内联依赖的课程模块，返回 (模块名, 源码)。第二个返回值是无法内联的模块名
+fn dependency_modules(lesson: &dyn Lesson) -> (Vec<(String, String)>, Vec<String>) {
+    let mut pending: VecDeque<String> = corpus::lesson_lines(lesson)
+        .into_iter()
+        .flat_map(|line| crate_references(&rewrite_paths(line)))
+        .collect();
+    let mut inlined: Vec<String> = Vec::new();
+    let mut modules = Vec::new();
+    let mut unresolved = Vec::new();
+
+    // 按第一次被引用的顺序内联。同一个单元格里的模块互相引用不受先后顺序的影响
+    while let Some(id) = pending.pop_front() {
+        if id == lesson.id() || inlined.contains(&id) || unresolved.contains(&id) {
+            continue;
+        }
+        let Some(dependency) = lesson::find(&id) else {
+            unresolved.push(id);
+            continue;
+        };
+        let body: Vec<String> = module_items(dependency.as_ref()).into_iter().map(rewrite_paths).collect();
+        pending.extend(body.iter().flat_map(|line| crate_references(line)));
+        let indented: Vec<String> = body
+            .iter()
+            .map(|line| if line.is_empty() { String::new() } else { format!("    {}", line) })
+            .collect();
+        let code = format!("// {}\nmod {} {{\n{}\n}}", dependency.path(), id, indented.join("\n").trim_end());
+        modules.push((id.clone(), code));
+        inlined.push(id);
+    }
+    (modules, unresolved)
+}
+
+fn lesson_cells(lesson: &dyn Lesson) -> Vec<Cell> {
+    let mut cells = Vec::new();
+
+    // README 正文：标题层级整体提升，使章节标题成为笔记本的一级标题
+    for section in lesson.sections() {
+        let mut text = format!("{} {}", "#".repeat(section.level.saturating_sub(2).max(1)), section.title);
+        let mut in_code = false;
+        for &(_, line) in &section.lines {
+            if line.trim_start().starts_with("```") {
+                in_code = !in_code;
+                continue;
+            }
+            // 去掉代码块后留下的连续空行只保留一个
+            let repeated_blank = line.trim().is_empty() && text.ends_with('\n');
+            if !in_code && !repeated_blank {
+                text.push('\n');
+                text.push_str(line);
+            }
+        }
+        cells.push(Cell::Markdown(text.trim_end().to_string()));
+    }
+
+    let (modules, _) = dependency_modules(lesson);
+    if !modules.is_empty() {
+        let names: Vec<&str> = modules.iter().map(|(id, _)| id.as_str()).collect();
+        let code: Vec<&str> = modules.iter().map(|(_, code)| code.as_str()).collect();
+        cells.push(Cell::Markdown(format!(
+            "### 依赖\n\n这节课用到了其他课程中定义的类型（{}），它们的源码内联在下面。先运行这个单元格。",
+            names.join("、")
+        )));
+        cells.push(Cell::Code(code.join("\n\n")));
+    }
+
+    let parts = corpus::split_lesson(lesson);
+    // 直接导入模块本身的 `use crate::traits;` 和内联的 `mod traits` 重名，去掉
+    let rewritten: Vec<String> = parts
+        .definitions
+        .iter()
+        .map(|line| rewrite_paths(line))
+        .filter(|line| {
+            let path = line.trim().strip_prefix("use crate::").and_then(|rest| rest.strip_suffix(';'));
+            !path.is_some_and(|path| path.chars().all(|c| c.is_alphanumeric() || c == '_'))
+        })
+        .collect();
+    let rewritten: Vec<&str> = rewritten.iter().map(String::as_str).collect();
+    let definitions = corpus::dedent(&rewritten);
+    if !definitions.trim().is_empty() {
+        cells.push(Cell::Markdown("### 定义\n\n课程中用到的类型、函数和宏。先运行这个单元格。".to_string()));
+        cells.push(Cell::Code(definitions.trim().to_string()));
+    }
+
+    let sections = corpus::split_sections(&parts.statements);
+    for (start, end) in group_sections(&sections) {
+        let notes = comment_text(&sections[start].notes);
+        if !notes.trim().is_empty() {
+            cells.push(Cell::Markdown(notes));
+        }
+
+        let mut code: Vec<&str> = sections[start].code.clone();
+        for section in &sections[start + 1..=end] {
+            code.extend(section.notes.iter().copied());
+            code.extend(section.code.iter().copied());
+        }
+        let Some(first) = code.iter().position(|line| !line.trim().is_empty()) else {
+            continue;
+        };
+        let body = corpus::dedent(&code[first..]);
+        let indented: Vec<String> = body
+            .trim_end()
+            .lines()
+            .map(|line| if line.is_empty() { String::new() } else { format!("    {}", line) })
+            .collect();
+        cells.push(Cell::Code(format!("{{\n{}\n}}", indented.join("\n"))));
+    }
+
+    cells
+}
+
+// `notebook [out_dir]` 命令的入口
+pub fn run(args: &[String]) {
+    let out_dir = Path::new(args.first().map(String::as_str).unwrap_or("notebooks"));
+    if let Err(error) = fs::create_dir_all(out_dir) {
+        eprintln!("无法创建目录 {}: {}", out_dir.display(), error);
+        std::process::exit(1);
+    }
+
+    for lesson in lesson::registry() {
+        let (_, unresolved) = dependency_modules(lesson.as_ref());
+        if !unresolved.is_empty() {
+            eprintln!("跳过 {}：引用了无法内联的模块 {}", lesson.path(), unresolved.join(", "));
+            continue;
+        }
+        let cells = lesson_cells(lesson.as_ref());
+        let path = out_dir.join(format!("{}.ipynb", lesson.id()));
+        match fs::write(&path, notebook_json(&cells)) {
+            Ok(()) => println!("{} -> {}（{} 个单元格）", lesson.path(), path.display(), cells.len()),
+            Err(error) => {
+                eprintln!("无法写入 {}: {}", path.display(), error);
+                std::process::exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+
+use super::corpus::{self, README_PATH};
+use crate::lesson;
+
+// 全文搜索（Full-Text Search）
+// 对 README 章节和课程源码建立倒排索引（inverted index）：
+// 词项（term）-> 出现过它的文档列表。这里的“文档”是一行文本，
+// 这样每个命中结果都能给出精确的 文件:行号。
+//
+// 分词规则：
+// - 英文和标识符按单词切分并转为小写，`takes_ownership`、`NewsArticle`
+//   这样的标识符还会额外拆成 `takes`/`ownership`、`news`/`article`。
+// - 中文没有空格分词，这里使用字符二元组（bigram）：`借用规则` -> `借用`、`用规`、`规则`。
+//   单个汉字组成的片段则保留这个字本身。
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DocKind {
+    Heading,
+    Prose,
+    Comment,
+    Code,
+}
+
+impl DocKind {
+    // 标题命中比正文更有价值，正文和注释比代码更适合作为概念解释
+    fn weight(self) -> f64 {
+        match self {
+            DocKind::Heading => 3.0,
+            DocKind::Prose => 1.5,
+            DocKind::Comment => 1.3,
+            DocKind::Code => 1.0,
+        }
+    }
+}
+
+struct Document {
+    path: &'static str,
+    line: usize,
+    context: String,
+    kind: DocKind,
+    text: &'static str,
+}
+
+pub struct Hit<'a> {
+    pub path: &'a str,
+    pub line: usize,
+    pub context: &'a str,
+    pub text: &'a str,
+    pub score: f64,
+}
+
+pub struct SearchIndex {
+    documents: Vec<Document>,
+    // 词项 -> (文档编号, 词频)
+    postings: HashMap<String, Vec<(usize, usize)>>,
+}
+
+impl SearchIndex {
+    pub fn build() -> SearchIndex {
+        let mut index = SearchIndex {
+            documents: Vec::new(),
+            postings: HashMap::new(),
+        };
+
+        for section in corpus::readme_sections() {
+            index.add(Document {
+                path: README_PATH,
+                line: section.line,
+                context: section.title.to_string(),
+                kind: DocKind::Heading,
+                text: section.title,
+            });
+            for &(line, text) in &section.lines {
+                index.add(Document {
+                    path: README_PATH,
+                    line,
+                    context: section.title.to_string(),
+                    kind: DocKind::Prose,
+                    text,
+                });
+            }
+        }
+
+        for lesson in lesson::registry() {
+            for (number, text) in lesson.source().lines().enumerate() {
+                let kind = if text.trim_start().starts_with("//") {
+                    DocKind::Comment
+                } else {
+                    DocKind::Code
+                };
+                index.add(Document {
+                    path: lesson.path(),
+                    line: number + 1,
+                    context: format!("{}/{}", lesson.group(), lesson.id()),
+                    kind,
+                    text,
+                });
+            }
+        }
+
+        index
+    }
+
+    fn add(&mut self, document: Document) {
+        let id = self.documents.len();
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for term in tokenize(document.text) {
+            *counts.entry(term).or_insert(0) += 1;
+        }
+        if counts.is_empty() {
+            return;
+        }
+        for (term, count) in counts {
+            self.postings.entry(term).or_default().push((id, count));
+        }
+        self.documents.push(document);
+    }
+
+    // 查询词中的单个汉字没有对应的二元组，展开成所有包含该字的索引词项
+    fn expand(&self, term: &str) -> Vec<&str> {
+        let mut chars = term.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if is_cjk(c) => {
+                let mut terms: Vec<&str> = self
+                    .postings
+                    .keys()
+                    .filter(|key| key.contains(c))
+                    .map(String::as_str)
+                    .collect();
+                terms.sort();
+                terms
+            }
+            _ => self
+                .postings
+                .get_key_value(term)
+                .map(|(key, _)| vec![key.as_str()])
+                .unwrap_or_default(),
+        }
+    }
+
+    // 打分方式是 TF-IDF 的简化版：
+    // score = Σ idf(词项) × (1 + ln 词频) × 文档类型权重，
+    // 再乘以查询词项的覆盖率（匹配的词项越多越靠前），
+    // 整个查询原样出现在该行中时再额外加倍。
+    pub fn search(&self, query: &str, limit: usize) -> Vec<Hit<'_>> {
+        let mut terms = tokenize(query);
+        terms.sort();
+        terms.dedup();
+        if terms.is_empty() {
+            return Vec::new();
+        }
+
+        let total = self.documents.len() as f64;
+        let mut scores: HashMap<usize, (f64, usize)> = HashMap::new();
+
+        for term in &terms {
+            let mut matched: HashMap<usize, f64> = HashMap::new();
+            for key in self.expand(term) {
+                let postings = &self.postings[key];
+                let idf = (total / postings.len() as f64).ln() + 1.0;
+                for &(doc, count) in postings {
+                    let weight = self.documents[doc].kind.weight();
+                    let score = idf * (1.0 + (count as f64).ln()) * weight;
+                    let best = matched.entry(doc).or_insert(0.0);
+                    *best = best.max(score);
+                }
+            }
+            for (doc, score) in matched {
+                let entry = scores.entry(doc).or_insert((0.0, 0));
+                entry.0 += score;
+                entry.1 += 1;
+            }
+        }
+
+        let phrase = query.trim().to_lowercase();
+        let mut hits: Vec<Hit<'_>> = scores
+            .into_iter()
+            .map(|(doc, (score, matched))| {
+                let document = &self.documents[doc];
+                let coverage = matched as f64 / terms.len() as f64;
+                let mut score = score * coverage * coverage;
+                if document.text.to_lowercase().contains(&phrase) {
+                    score *= 2.0;
+                }
+                Hit {
+                    path: document.path,
+                    line: document.line,
+                    context: &document.context,
+                    text: document.text.trim(),
+                    score,
+                }
+            })
+            .collect();
+
+        hits.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.path.cmp(b.path))
+                .then_with(|| a.line.cmp(&b.line))
+        });
+        hits.truncate(limit);
+        hits
+    }
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{4E00}'..='\u{9FFF}'
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{F900}'..='\u{FAFF}')
+}
+
+pub fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+    let mut han: Vec<char> = Vec::new();
+
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            flush_han(&mut han, &mut tokens);
+            word.push(c);
+        } else if is_cjk(c) {
+            flush_word(&mut word, &mut tokens);
+            han.push(c);
+        } else {
+            flush_word(&mut word, &mut tokens);
+            flush_han(&mut han, &mut tokens);
+        }
+    }
+    flush_word(&mut word, &mut tokens);
+    flush_han(&mut han, &mut tokens);
+
+    tokens
+}
+
+fn flush_word(word: &mut String, tokens: &mut Vec<String>) {
+    let trimmed = word.trim_matches('_');
+    if trimmed.is_empty() {
+        word.clear();
+        return;
+    }
+
+    // 按下划线和驼峰边界拆出子词：`takes_ownership`、`NewsArticle`
+    let mut parts: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut previous_lower = false;
+    for c in trimmed.chars() {
+        let boundary = c == '_' || (c.is_ascii_uppercase() && previous_lower);
+        if boundary && !current.is_empty() {
+            parts.push(current.to_lowercase());
+            current.clear();
+        }
+        if c != '_' {
+            current.push(c);
+        }
+        previous_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+    }
+    if !current.is_empty() {
+        parts.push(current.to_lowercase());
+    }
+
+    tokens.push(trimmed.to_lowercase());
+    if parts.len() > 1 {
+        tokens.extend(parts);
+    }
+    word.clear();
+}
+
+fn flush_han(han: &mut Vec<char>, tokens: &mut Vec<String>) {
+    if han.len() == 1 {
+        tokens.push(han[0].to_string());
+    } else {
+        for pair in han.windows(2) {
+            tokens.push(pair.iter().collect());
+        }
+    }
+    han.clear();
+}
+
+// `search <term>` 命令的入口
+pub fn run(args: &[String]) {
+    let query = args.join(" ");
+    if query.trim().is_empty() {
+        eprintln!("用法: search <term>，例如 search 借用 或 search trait object");
+        std::process::exit(2);
+    }
+
+    let index = SearchIndex::build();
+    let hits = index.search(&query, 20);
+    if hits.is_empty() {
+        println!("没有找到与 \"{}\" 相关的内容。", query);
+        return;
+    }
+
+    println!("\"{}\" 的搜索结果（共 {} 条）：", query, hits.len());
+    for hit in hits {
+        let text: String = hit.text.chars().take(72).collect();
+        println!(
+            "{:>6.2}  {}:{}  [{}] {}",
+            hit.score, hit.path, hit.line, hit.context, text
+        );
+    }
+}